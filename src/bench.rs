@@ -0,0 +1,165 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part 1",
+            Stage::Part2 => "part 2",
+        })
+    }
+}
+
+/// how long each stage took in a single iteration
+pub type Sample = [(Stage, Duration); 3];
+
+/// parses the input and solves both parts, timing each stage separately
+pub fn sample<S: Solution>(input: &str) -> Result<Sample, ParseError> {
+    let start = Instant::now();
+    let solution = parse::<S>(black_box(input))?;
+    let parse = start.elapsed();
+    let start = Instant::now();
+    black_box(solution.part1());
+    let part1 = start.elapsed();
+    let start = Instant::now();
+    black_box(solution.part2());
+    let part2 = start.elapsed();
+    Ok([
+        (Stage::Parse, parse),
+        (Stage::Part1, part1),
+        (Stage::Part2, part2),
+    ])
+}
+
+pub type Sampler = fn(&str) -> Result<Sample, ParseError>;
+
+/// every day as `(day, sampler)`, in order
+pub const SAMPLERS: &[(u8, Sampler)] = &[
+    (1, sample::<day01::Lists>),
+    (2, sample::<day02::Reports>),
+    (3, sample::<day03::Memory>),
+    (4, sample::<day04::Input>),
+    (5, sample::<day05::Input>),
+    (6, sample::<day06::Input>),
+    (7, sample::<day07::Equations>),
+    (8, sample::<day08::Input>),
+    (9, sample::<day09::DiskMap>),
+    (10, sample::<day10::Input>),
+    (11, sample::<day11::Arrangement>),
+    (12, sample::<day12::Input>),
+    (13, sample::<day13::Arcade>),
+    (14, sample::<day14::Robots>),
+    (15, sample::<day15::Input>),
+    (16, sample::<day16::Input>),
+    (17, sample::<day17::Program<u64, ()>>),
+    (18, sample::<day18::Bytes>),
+    (19, sample::<day19::Towels>),
+    (20, sample::<day20::Racetrack>),
+    (21, sample::<day21::Codes>),
+    (22, sample::<day22::Buyers>),
+    (23, sample::<day23::Graph>),
+    (24, sample::<day24::Circuit>),
+    (25, sample::<day25::Door>),
+];
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut durations: Vec<Duration>) -> Self {
+        durations.sort();
+        Self {
+            min: durations[0],
+            median: durations[durations.len() / 2],
+            max: durations[durations.len() - 1],
+        }
+    }
+}
+
+/// stats for every benchmarked day and stage, stored as JSON baselines
+pub type Report = BTreeMap<u8, BTreeMap<Stage, Stats>>;
+
+/// runs a day `iterations` times and summarizes how long each stage took
+pub fn bench(
+    sampler: Sampler,
+    input: &str,
+    iterations: usize,
+) -> Result<BTreeMap<Stage, Stats>, ParseError> {
+    assert!(iterations > 0);
+    let mut durations: BTreeMap<Stage, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..iterations {
+        for (stage, duration) in sampler(input)? {
+            durations.entry(stage).or_default().push(duration);
+        }
+    }
+    Ok(durations
+        .into_iter()
+        .map(|(stage, durations)| (stage, Stats::new(durations)))
+        .collect())
+}
+
+pub struct Regression {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+/// stages whose median got slower than the baseline's by more than `tolerance`, e.g. 0.1 for 10%
+pub fn regressions(baseline: &Report, current: &Report, tolerance: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .flat_map(|(&day, stages)| {
+            stages.iter().filter_map(move |(&stage, stats)| {
+                let baseline = baseline.get(&day)?.get(&stage)?.median;
+                let slower =
+                    stats.median.as_secs_f64() > baseline.as_secs_f64() * (1.0 + tolerance);
+                slower.then_some(Regression {
+                    day,
+                    stage,
+                    baseline,
+                    current: stats.median,
+                })
+            })
+        })
+        .collect()
+}
+
+/// durations as whole nanoseconds, which is easier to read and diff than serde's default
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    env, fs,
+    panic::catch_unwind,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use advent_of_code_2024::{
+    bench::{self, Report, SAMPLERS},
+    flags,
+    inputs::Inputs,
+    parallel, Solver, SOLVERS,
+};
+
+const USAGE: &str = "usage: aoc run <day|all> [part] [--inputs <dir>] [--threads <n>]
+       aoc bench <day|all> [--iterations <n>] [--save <file>] [--baseline <file>] [--tolerance <percent>] [--inputs <dir>] [--threads <n>]";
+
+/// environment variable holding the session cookie used to download missing inputs
+const SESSION: &str = "AOC_SESSION";
+
+enum Command {
+    Run {
+        part: Option<u8>,
+    },
+    Bench {
+        iterations: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        /// how much slower than the baseline a stage may get before it is flagged, e.g. 0.1 for 10%
+        tolerance: f64,
+    },
+}
+
+struct Args {
+    command: Command,
+    day: Option<u8>,
+    inputs: PathBuf,
+    /// how many threads to use, or 0 for one per CPU
+    threads: usize,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut command = match args.next().as_deref() {
+            Some("run") => Command::Run { part: None },
+            Some("bench") => Command::Bench {
+                iterations: 10,
+                save: None,
+                baseline: None,
+                tolerance: 0.1,
+            },
+            _ => return Err(USAGE.to_owned()),
+        };
+        let mut positional = vec![];
+        let mut inputs = PathBuf::from("inputs");
+        let mut threads = 0;
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), &mut command) {
+                ("--inputs", _) => inputs = args.next().ok_or(USAGE)?.into(),
+                ("--threads", _) => {
+                    threads = flags::number(&arg, args.next())?;
+                    if threads == 0 {
+                        return Err("--threads must be at least 1".to_owned());
+                    }
+                }
+                ("--iterations", Command::Bench { iterations, .. }) => {
+                    *iterations = flags::number(&arg, args.next())?;
+                    if *iterations == 0 {
+                        return Err("--iterations must be at least 1".to_owned());
+                    }
+                }
+                ("--save", Command::Bench { save, .. }) => {
+                    *save = Some(args.next().ok_or(USAGE)?.into())
+                }
+                ("--baseline", Command::Bench { baseline, .. }) => {
+                    *baseline = Some(args.next().ok_or(USAGE)?.into())
+                }
+                ("--tolerance", Command::Bench { tolerance, .. }) => {
+                    *tolerance = flags::number::<f64>(&arg, args.next())? / 100.0
+                }
+                (flag, _) if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        let day = match (&positional[..], &mut command) {
+            ([day], _) => day,
+            ([day, part], Command::Run { part: selected }) => {
+                *selected = Some(
+                    part.parse()
+                        .ok()
+                        .filter(|part| (1..=2).contains(part))
+                        .ok_or_else(|| format!("invalid part: {part}"))?,
+                );
+                day
+            }
+            _ => return Err(USAGE.to_owned()),
+        };
+        let day = match day.as_str() {
+            "all" => None,
+            day => Some(
+                day.parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("invalid day: {day}"))?,
+            ),
+        };
+        Ok(Self {
+            command,
+            day,
+            inputs,
+            threads,
+        })
+    }
+}
+
+/// what a solver that panicked was complaining about, like "there is no way to the end"
+fn panicked(panic: Box<dyn Any + Send>) -> String {
+    let message = panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str));
+    match message {
+        Some(message) => format!("solver panicked: {message}"),
+        None => "solver panicked".to_owned(),
+    }
+}
+
+fn run(solver: Solver, path: &Path, input: &str) -> Result<String, String> {
+    catch_unwind(|| solver(input))
+        .map_err(panicked)?
+        .map_err(|err| err.report(path.display(), input))
+}
+
+fn solve(inputs: &Inputs, day: Option<u8>, part: Option<u8>) -> ExitCode {
+    let selected = SOLVERS
+        .iter()
+        .filter(|&&(d, p, _)| day.is_none_or(|day| d == day) && part.is_none_or(|part| p == part))
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        eprintln!("no solution for the selected day and part");
+        return ExitCode::FAILURE;
+    }
+    // fetch inputs one at a time to go easy on the server, then solve the days concurrently
+    let mut loaded = BTreeMap::new();
+    for &&(day, _, _) in &selected {
+        loaded
+            .entry(day)
+            .or_insert_with(|| inputs.get(day).map_err(|err| err.to_string()));
+    }
+    let answers = parallel::map(&selected, |&&(day, _, solver)| {
+        let input = loaded[&day].as_ref().map_err(Clone::clone)?;
+        run(solver, &inputs.path(day), input)
+    });
+    let mut failed = false;
+    for (&&(day, part, _), answer) in selected.iter().zip(answers) {
+        match answer {
+            Ok(answer) => println!("day {day:02} part {part}: {answer}"),
+            Err(err) => {
+                failed = true;
+                println!("day {day:02} part {part}: FAILED: {err}");
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn read_report(path: &Path) -> Result<Report, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    serde_json::from_str(&json).map_err(|err| format!("could not parse {}: {err}", path.display()))
+}
+
+fn write_report(path: &Path, report: &Report) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).unwrap();
+    fs::write(path, json + "\n").map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+fn benchmark(
+    inputs: &Inputs,
+    day: Option<u8>,
+    iterations: usize,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    tolerance: f64,
+) -> ExitCode {
+    let baseline = match baseline.map(read_report).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut failed = false;
+    let mut report = Report::new();
+    for &(d, sampler) in SAMPLERS
+        .iter()
+        .filter(|&&(d, _)| day.is_none_or(|day| d == day))
+    {
+        let stats = inputs
+            .get(d)
+            .map_err(|err| err.to_string())
+            .and_then(|input| {
+                catch_unwind(|| bench::bench(sampler, &input, iterations))
+                    .map_err(panicked)?
+                    .map_err(|err| err.report(inputs.path(d).display(), &input))
+            });
+        match stats {
+            Ok(stats) => {
+                for (stage, stats) in &stats {
+                    println!(
+                        "day {d:02} {stage:<6}: min {:>10.1?}  median {:>10.1?}  max {:>10.1?}",
+                        stats.min, stats.median, stats.max
+                    );
+                }
+                report.insert(d, stats);
+            }
+            Err(err) => {
+                failed = true;
+                println!("day {d:02}: FAILED: {err}");
+            }
+        }
+    }
+    if let Some(baseline) = &baseline {
+        let regressions = bench::regressions(baseline, &report, tolerance);
+        for regression in &regressions {
+            println!(
+                "REGRESSION day {:02} {}: median {:.1?} vs {:.1?} in the baseline",
+                regression.day, regression.stage, regression.current, regression.baseline
+            );
+        }
+        failed |= !regressions.is_empty();
+    }
+    if let Some(save) = save {
+        if let Err(err) = write_report(save, &report) {
+            eprintln!("{err}");
+            failed = true;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    parallel::set_threads(args.threads);
+    let mut inputs = Inputs::new(args.inputs);
+    if let Ok(session) = env::var(SESSION) {
+        inputs = inputs.with_session(session.trim());
+    }
+    match args.command {
+        Command::Run { part } => solve(&inputs, args.day, part),
+        Command::Bench {
+            iterations,
+            save,
+            baseline,
+            tolerance,
+        } => benchmark(
+            &inputs,
+            args.day,
+            iterations,
+            save.as_deref(),
+            baseline.as_deref(),
+            tolerance,
+        ),
+    }
+}
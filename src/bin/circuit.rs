@@ -0,0 +1,177 @@
+use std::{collections::BTreeSet, env, fs, process::ExitCode};
+
+use advent_of_code_2024::{
+    day24::{Circuit, Target},
+    flags, parse,
+};
+
+const USAGE: &str = "usage: circuit dot <file> [--target <add|and|expression>]
+       circuit verilog <file> [--module <name>]
+       circuit test <file> [--target <add|and|expression>] [--random <count>]
+       circuit repair <file> [--target <add|and|expression>] [--max-swaps <count>]
+       circuit prove <file> [--target <add|and|expression>] [--max-swaps <count>]";
+
+enum Command {
+    Dot {
+        target: Target,
+    },
+    Verilog {
+        module: String,
+    },
+    Test {
+        target: Target,
+        random: usize,
+    },
+    Repair {
+        target: Target,
+        max_swaps: usize,
+    },
+    Prove {
+        target: Target,
+        max_swaps: Option<usize>,
+    },
+}
+
+struct Args {
+    command: Command,
+    file: String,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut command = match args.next().as_deref() {
+            Some("dot") => Command::Dot {
+                target: Target::default(),
+            },
+            Some("verilog") => Command::Verilog {
+                module: "circuit".to_owned(),
+            },
+            Some("test") => Command::Test {
+                target: Target::default(),
+                random: 10_000,
+            },
+            Some("repair") => Command::Repair {
+                target: Target::default(),
+                max_swaps: 4,
+            },
+            Some("prove") => Command::Prove {
+                target: Target::default(),
+                max_swaps: None,
+            },
+            _ => return Err(USAGE.to_owned()),
+        };
+        let mut positional = vec![];
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), &mut command) {
+                (
+                    "--target",
+                    Command::Dot { target }
+                    | Command::Test { target, .. }
+                    | Command::Repair { target, .. }
+                    | Command::Prove { target, .. },
+                ) => {
+                    let expression = args.next().ok_or(USAGE)?;
+                    *target = expression
+                        .parse()
+                        .map_err(|err| format!("invalid value for --target: {err}"))?;
+                }
+                ("--module", Command::Verilog { module }) => *module = args.next().ok_or(USAGE)?,
+                ("--random", Command::Test { random, .. }) => {
+                    *random = flags::number(&arg, args.next())?
+                }
+                ("--max-swaps", Command::Repair { max_swaps, .. }) => {
+                    *max_swaps = flags::number(&arg, args.next())?
+                }
+                ("--max-swaps", Command::Prove { max_swaps, .. }) => {
+                    *max_swaps = Some(flags::number(&arg, args.next())?)
+                }
+                (flag, _) if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        match <[String; 1]>::try_from(positional) {
+            Ok([file]) => Ok(Self { command, file }),
+            Err(_) => Err(USAGE.to_owned()),
+        }
+    }
+}
+
+fn execute(file: &str, input: &str, command: Command) -> Result<String, String> {
+    let circuit = parse::<Circuit>(input).map_err(|err| err.report(file, input))?;
+    Ok(match command {
+        Command::Dot { target } => {
+            let circuit = circuit.with_target(target);
+            let suspects: BTreeSet<String> = circuit.suspects();
+            circuit.to_dot(&suspects)
+        }
+        Command::Verilog { module } => circuit.to_verilog(&module),
+        Command::Test { target, random } => {
+            circuit
+                .with_target(target)
+                .test(random)
+                .map_err(|err| err.to_string())?;
+            "every output is right\n".to_owned()
+        }
+        Command::Repair { target, max_swaps } => {
+            match circuit.with_target(target).repair(max_swaps) {
+                Some(swaps) if swaps.is_empty() => "every output is right\n".to_owned(),
+                Some(swaps) => format_swaps(swaps),
+                None => format!("no {max_swaps} swaps or fewer repair the circuit\n"),
+            }
+        }
+        Command::Prove { target, max_swaps } => prove(circuit.with_target(target), max_swaps)?,
+    })
+}
+
+/// proves the circuit right for every input, or with `max_swaps`, finds swaps that make it right
+/// and proves those
+#[cfg(feature = "z3")]
+fn prove(circuit: Circuit, max_swaps: Option<usize>) -> Result<String, String> {
+    match max_swaps {
+        None => {
+            circuit.prove().map_err(|err| err.to_string())?;
+            Ok("every output is right for every input\n".to_owned())
+        }
+        Some(max_swaps) => {
+            let swaps = circuit
+                .proven_repair(max_swaps)
+                .ok_or_else(|| format!("no {max_swaps} swaps or fewer repair the circuit"))?;
+            Ok(format_swaps(swaps))
+        }
+    }
+}
+
+fn format_swaps(swaps: Vec<(String, String)>) -> String {
+    swaps
+        .into_iter()
+        .map(|(a, b)| format!("{a} <-> {b}\n"))
+        .collect()
+}
+
+#[cfg(not(feature = "z3"))]
+fn prove(_: Circuit, _: Option<usize>) -> Result<String, String> {
+    Err("circuit prove needs Z3, so build with --features z3".to_owned())
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = fs::read_to_string(&args.file)
+        .map_err(|err| format!("could not read {}: {err}", args.file))
+        .and_then(|input| execute(&args.file, &input, args.command));
+    match result {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{env, fs, process::ExitCode};
+
+use advent_of_code_2024::{
+    day21::{Codes, Keypad},
+    flags, parse, ParseError,
+};
+
+const USAGE: &str =
+    "usage: keypad <code>... [--robots <n>] [--numeric <file>] [--directional <file>]";
+
+/// the most presses worth printing on one line
+const MAX_PRESSES: usize = 1_000_000;
+
+struct Args {
+    codes: Vec<String>,
+    robots: usize,
+    numeric: Option<String>,
+    directional: Option<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            codes: vec![],
+            robots: 2,
+            numeric: None,
+            directional: None,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--robots" => parsed.robots = flags::number("--robots", args.next())?,
+                "--numeric" => parsed.numeric = Some(args.next().ok_or(USAGE)?),
+                "--directional" => parsed.directional = Some(args.next().ok_or(USAGE)?),
+                flag if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => parsed.codes.push(arg),
+            }
+        }
+        if parsed.codes.is_empty() {
+            return Err(USAGE.to_owned());
+        }
+        Ok(parsed)
+    }
+}
+
+/// the layout in `file`, or `default` if there's no file
+fn keypad(file: Option<&str>, default: Keypad) -> Result<Keypad, String> {
+    let Some(file) = file else {
+        return Ok(default);
+    };
+    let layout = fs::read_to_string(file).map_err(|err| format!("could not read {file}: {err}"))?;
+    layout
+        .parse()
+        .map_err(|err: ParseError| err.report(file, &layout))
+}
+
+/// prints the presses on every layer that type each code, from the door down to yours
+fn show(args: &Args) -> Result<(), String> {
+    let input = args.codes.join("\n");
+    let codes = parse::<Codes>(&input)
+        .map_err(|err| err.report("the codes", &input))?
+        .with_keypads(
+            keypad(args.numeric.as_deref(), Keypad::numeric())?,
+            keypad(args.directional.as_deref(), Keypad::directional())?,
+        );
+    for code in &args.codes {
+        let presses = codes
+            .presses(code, args.robots)
+            .ok_or_else(|| format!("the keypads can't type {code}"))?;
+        if presses > MAX_PRESSES {
+            return Err(format!("{code} takes {presses} presses, too many to print"));
+        }
+        let sequences = codes.sequences(code, args.robots).unwrap();
+        println!("{code}: {presses} presses");
+        for (layer, sequence) in sequences.iter().enumerate().rev() {
+            let presser = match layer {
+                0 => "you".to_owned(),
+                _ => format!("robot {layer}"),
+            };
+            println!("  {presser:<9} {sequence}");
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    match show(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::{env, fs, path::Path, process::ExitCode};
+
+use advent_of_code_2024::{day16::Input, flags, parse};
+
+const USAGE: &str = "usage: maze <input> [--turn <cost>] [--step <cost>] [--u-turns] [--route] \
+                     [--image <file.png|file.ppm>] [--scale <n>]";
+
+struct Args {
+    file: String,
+    turn: usize,
+    step: usize,
+    u_turns: bool,
+    /// whether to print the moves along one of the best routes
+    route: bool,
+    /// where to draw the maze as a picture
+    image: Option<String>,
+    scale: usize,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self {
+            file: String::new(),
+            turn: 1000,
+            step: 1,
+            u_turns: false,
+            route: false,
+            image: None,
+            scale: 4,
+        };
+        let mut positional = vec![];
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--turn" => parsed.turn = flags::number("--turn", args.next())?,
+                "--step" => parsed.step = flags::number("--step", args.next())?,
+                "--u-turns" => parsed.u_turns = true,
+                "--route" => parsed.route = true,
+                "--image" => parsed.image = Some(args.next().ok_or(USAGE)?),
+                "--scale" => parsed.scale = flags::number::<usize>("--scale", args.next())?.max(1),
+                flag if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        let Ok([file]) = <[String; 1]>::try_from(positional) else {
+            return Err(USAGE.to_owned());
+        };
+        Ok(Self { file, ..parsed })
+    }
+}
+
+/// the best score and the maze with the tiles on the best routes marked, and one route if asked
+fn solve(args: &Args, input: &str) -> Result<String, String> {
+    let maze = parse::<Input>(input)
+        .map_err(|err| err.report(&args.file, input))?
+        .with_turn_cost(args.turn)
+        .with_step_cost(args.step)
+        .with_u_turns(args.u_turns);
+    let solved = maze.solve();
+    let (score, route) = solved
+        .score()
+        .zip(solved.route())
+        .ok_or("there is no way to the end")?;
+    let mut output = format!(
+        "score {score}, {} tiles on the best routes\n{}",
+        solved.seats(),
+        solved.picture()
+    );
+    if args.route {
+        output += &format!(
+            "route: {}\n",
+            route.iter().map(|m| m.letter()).collect::<String>()
+        );
+    }
+    if let Some(file) = &args.image {
+        let image = solved.image().scaled(args.scale);
+        let bytes = match Path::new(file).extension().and_then(|ext| ext.to_str()) {
+            Some("png") => image.to_png(),
+            Some("ppm") => image.to_ppm(),
+            _ => return Err(format!("{file} should end in .png or .ppm")),
+        };
+        fs::write(file, bytes).map_err(|err| format!("could not write {file}: {err}"))?;
+    }
+    Ok(output)
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = fs::read_to_string(&args.file)
+        .map_err(|err| format!("could not read {}: {err}", args.file))
+        .and_then(|input| solve(&args, &input));
+    match result {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
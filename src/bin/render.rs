@@ -0,0 +1,153 @@
+use std::{env, fs, path::Path, process::ExitCode};
+
+use advent_of_code_2024::{
+    day14::Robots,
+    day15, day16, flags, parse,
+    render::{gif, Image},
+    ParseError,
+};
+
+const USAGE: &str = "usage: render <14|15|16> <input> <output.ppm|.png|.gif> [--scale <n>] \
+                     [--tick <n>] [--frames <n>] [--box <width>x<height>]";
+
+/// how long each frame of an animation shows, in hundredths of a second
+const DELAY: u16 = 10;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Ppm,
+    Png,
+    Gif,
+}
+
+struct Args {
+    day: u32,
+    input: String,
+    output: String,
+    format: Format,
+    scale: usize,
+    /// the tick to draw the robots at, rather than when they draw the tree
+    tick: Option<i64>,
+    /// how many frames an animation shows
+    frames: usize,
+    /// how many tiles each box in the warehouse covers across and down
+    size: (usize, usize),
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut scale = 4;
+        let mut tick = None;
+        let mut frames = 100;
+        let mut size = (1, 1);
+        let mut positional = vec![];
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--scale" => scale = flags::number("--scale", args.next())?,
+                "--tick" => tick = Some(flags::number("--tick", args.next())?),
+                "--frames" => frames = flags::number("--frames", args.next())?,
+                "--box" => size = flags::size("--box", args.next())?,
+                flag if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        let Ok([day, input, output]) = <[String; 3]>::try_from(positional) else {
+            return Err(USAGE.to_owned());
+        };
+        let day = day.parse().map_err(|_| format!("invalid day: {day}"))?;
+        let format = match Path::new(&output).extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => Format::Ppm,
+            Some("png") => Format::Png,
+            Some("gif") => Format::Gif,
+            _ => return Err(format!("{output} should end in .ppm, .png or .gif")),
+        };
+        if scale == 0 || frames == 0 {
+            return Err("--scale and --frames must be at least 1".to_owned());
+        }
+        Ok(Self {
+            day,
+            input,
+            output,
+            format,
+            scale,
+            tick,
+            frames,
+            size,
+        })
+    }
+}
+
+/// the pictures to write: one for a still image, or every frame of an animation
+///
+/// the robots are drawn at the tick they draw the tree, or the frames leading up to it, the
+/// warehouse after its last move, or before each of its first moves, and the maze with its best
+/// seats
+fn frames(args: &Args, input: &str) -> Result<Vec<Image>, String> {
+    let failure = |err: ParseError| err.report(&args.input, input);
+    let animated = matches!(args.format, Format::Gif);
+    match args.day {
+        14 => {
+            let robots = parse::<Robots>(input).map_err(failure)?;
+            let tick = match args.tick {
+                Some(tick) => tick,
+                None => robots.tree().ok_or("the robots never draw a tree")? as i64,
+            };
+            let first = if animated {
+                tick - args.frames as i64 + 1
+            } else {
+                tick
+            };
+            Ok((first.max(0)..=tick)
+                .map(|tick| robots.image(tick))
+                .collect())
+        }
+        15 => {
+            let warehouse = parse::<day15::Input>(input).map_err(failure)?;
+            let (width, height) = args.size;
+            if animated {
+                Ok(warehouse.frames(width, height).take(args.frames).collect())
+            } else {
+                Ok(vec![warehouse.replay(width, height).image()])
+            }
+        }
+        16 => {
+            let maze = parse::<day16::Input>(input).map_err(failure)?;
+            Ok(vec![maze.solve().image()])
+        }
+        day => Err(format!("day {day} has nothing to render")),
+    }
+}
+
+fn render(args: &Args) -> Result<(), String> {
+    let input = fs::read_to_string(&args.input)
+        .map_err(|err| format!("could not read {}: {err}", args.input))?;
+    let frames = frames(args, &input)?
+        .iter()
+        .map(|frame| frame.scaled(args.scale))
+        .collect::<Vec<_>>();
+    let first = frames.first().ok_or("there is nothing to draw")?;
+    let bytes = match args.format {
+        Format::Ppm => first.to_ppm(),
+        Format::Png => first.to_png(),
+        Format::Gif => gif(&frames, DELAY)
+            .ok_or("the frames have too many colours or are too big for a GIF")?,
+    };
+    fs::write(&args.output, bytes).map_err(|err| format!("could not write {}: {err}", args.output))
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    match render(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{env, fs, process::ExitCode};
+
+use itertools::Itertools;
+
+use advent_of_code_2024::{
+    day17::{Backend, Program},
+    flags, parse,
+    vm::{self, Machine},
+};
+
+const USAGE: &str = "usage: vm assemble <file>
+       vm disassemble <file>
+       vm run <file> [--a <value>] [--trace] [--max-steps <n>]
+       vm quine <file> [--backend <search|z3>]";
+
+enum Command {
+    Assemble,
+    Disassemble,
+    Run {
+        /// overrides register A from the input
+        a: Option<u64>,
+        trace: bool,
+        max_steps: Option<usize>,
+    },
+    Quine {
+        backend: Backend,
+    },
+}
+
+struct Args {
+    command: Command,
+    file: String,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut command = match args.next().as_deref() {
+            Some("assemble") => Command::Assemble,
+            Some("disassemble") => Command::Disassemble,
+            Some("run") => Command::Run {
+                a: None,
+                trace: false,
+                max_steps: None,
+            },
+            Some("quine") => Command::Quine {
+                backend: Backend::default(),
+            },
+            _ => return Err(USAGE.to_owned()),
+        };
+        let mut positional = vec![];
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), &mut command) {
+                ("--a", Command::Run { a, .. }) => *a = Some(flags::number(&arg, args.next())?),
+                ("--trace", Command::Run { trace, .. }) => *trace = true,
+                ("--max-steps", Command::Run { max_steps, .. }) => {
+                    *max_steps = Some(flags::number(&arg, args.next())?)
+                }
+                ("--backend", Command::Quine { backend }) => {
+                    let value = args.next().ok_or(USAGE)?;
+                    *backend = value
+                        .parse()
+                        .map_err(|err| format!("invalid value for --backend: {err}"))?;
+                }
+                (flag, _) if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        match <[String; 1]>::try_from(positional) {
+            Ok([file]) => Ok(Self { command, file }),
+            Err(_) => Err(USAGE.to_owned()),
+        }
+    }
+}
+
+/// prints assembler source as a day 17 input
+fn assemble(file: &str, source: &str) -> Result<(), String> {
+    let (registers, program) = vm::assemble(source).map_err(|err| err.report(file, source))?;
+    print!("{}", vm::format_input(registers, &program));
+    Ok(())
+}
+
+/// prints the program on the first `Program:` line of the input as assembler source, with the
+/// registers from the input if it has any
+fn disassemble(file: &str, input: &str) -> Result<(), String> {
+    let line = input
+        .lines()
+        .find(|line| line.starts_with("Program:"))
+        .ok_or_else(|| format!("no program in {file}"))?;
+    let program = vm::parse_program(line).map_err(|err| err.report(file, input))?;
+    let registers = vm::parse(input)
+        .map(|(registers, _)| registers)
+        .unwrap_or_default();
+    print!("{}", vm::to_source(registers, &program));
+    Ok(())
+}
+
+fn run(
+    file: &str,
+    input: &str,
+    a: Option<u64>,
+    trace: bool,
+    max_steps: Option<usize>,
+) -> Result<(), String> {
+    let (mut registers, program) = vm::parse(input).map_err(|err| err.report(file, input))?;
+    registers.a = a.unwrap_or(registers.a);
+    let mut machine = Machine::new(&program, registers);
+    if let Some(max_steps) = max_steps {
+        machine = machine.with_step_budget(max_steps);
+    }
+    let output = machine
+        .run_traced(|machine, instruction| {
+            if trace {
+                println!(
+                    "{:>6} {:>3}: {:<6} {}",
+                    machine.steps(),
+                    machine.ip,
+                    instruction.to_string(),
+                    machine.registers
+                );
+            }
+        })
+        .map_err(|err| err.to_string())?;
+    println!("{}", output.iter().join(","));
+    Ok(())
+}
+
+/// prints the smallest value of register A for which the program outputs itself
+fn quine(file: &str, input: &str, backend: Backend) -> Result<(), String> {
+    let program = parse::<Program<u64, ()>>(input).map_err(|err| err.report(file, input))?;
+    let a = program
+        .quine(backend)
+        .ok_or("no value of register A makes the program output itself")?;
+    println!("{a}");
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = fs::read_to_string(&args.file)
+        .map_err(|err| format!("could not read {}: {err}", args.file))
+        .and_then(|input| match args.command {
+            Command::Assemble => assemble(&args.file, &input),
+            Command::Disassemble => disassemble(&args.file, &input),
+            Command::Run {
+                a,
+                trace,
+                max_steps,
+            } => run(&args.file, &input, a, trace, max_steps),
+            Command::Quine { backend } => quine(&args.file, &input, backend),
+        });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
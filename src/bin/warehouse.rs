@@ -0,0 +1,158 @@
+use std::{env, fs, process::ExitCode};
+
+use itertools::Itertools;
+
+use advent_of_code_2024::{
+    day15::{Input, MoveOutcome, Warehouse},
+    flags,
+    geometry::Direction,
+    parse,
+};
+
+const USAGE: &str = "usage: warehouse <input> [--box <width>x<height>] [--after <moves>]
+       warehouse <input> --compare [--box <width>x<height>]";
+
+enum Command {
+    /// print the warehouse after every move, or only after this many
+    Replay {
+        size: (usize, usize),
+        after: Option<usize>,
+    },
+    /// find the first move the robot makes differently with boxes of this size than with boxes
+    /// of a single tile
+    Compare { size: (usize, usize) },
+}
+
+struct Args {
+    command: Command,
+    file: String,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let (mut size, mut after, mut compare) = (None, None, false);
+        let mut positional = vec![];
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--box" => size = Some(flags::size("--box", args.next())?),
+                "--after" => after = Some(flags::number("--after", args.next())?),
+                "--compare" => compare = true,
+                flag if flag.starts_with("--") => return Err(USAGE.to_owned()),
+                _ => positional.push(arg),
+            }
+        }
+        let command = match (compare, after) {
+            (false, after) => Command::Replay {
+                size: size.unwrap_or((1, 1)),
+                after,
+            },
+            (true, None) => Command::Compare {
+                size: size.unwrap_or((2, 1)),
+            },
+            (true, Some(_)) => return Err(USAGE.to_owned()),
+        };
+        match <[String; 1]>::try_from(positional) {
+            Ok([file]) => Ok(Self { command, file }),
+            Err(_) => Err(USAGE.to_owned()),
+        }
+    }
+}
+
+fn describe(outcome: &MoveOutcome) -> String {
+    match outcome {
+        MoveOutcome::Blocked => "blocked".to_owned(),
+        MoveOutcome::Moved { pushed } if pushed.is_empty() => "moved".to_owned(),
+        MoveOutcome::Moved { pushed } => {
+            let boxes = pushed
+                .iter()
+                .map(|position| format!("{},{}", position.row, position.col))
+                .join(" ");
+            format!("pushed the boxes at {boxes}")
+        }
+    }
+}
+
+fn replay(
+    input: &Input,
+    (width, height): (usize, usize),
+    after: Option<usize>,
+) -> Result<String, String> {
+    let mut warehouse = input.warehouse(width, height);
+    let moves = input.moves();
+    let Some(after) = after else {
+        let mut output = format!("before any moves:\n{warehouse}");
+        for (i, &m) in moves.iter().enumerate() {
+            let outcome = warehouse.apply(m);
+            output += &format!("\nmove {} {}: {}\n", i + 1, m.arrow(), describe(&outcome));
+            output += &warehouse.to_string();
+        }
+        return Ok(output + &format!("\nGPS sum {}\n", warehouse.gps()));
+    };
+    if after > moves.len() {
+        return Err(format!("there are only {} moves", moves.len()));
+    }
+    for &m in &moves[..after] {
+        warehouse.apply(m);
+    }
+    Ok(format!(
+        "after {after} moves:\n{warehouse}\nGPS sum {}\n",
+        warehouse.gps()
+    ))
+}
+
+/// whether the robot got anywhere, which is all the two warehouses can agree on since their boxes
+/// are different sizes
+fn moved(warehouse: &mut Warehouse, m: Direction) -> bool {
+    matches!(warehouse.apply(m), MoveOutcome::Moved { .. })
+}
+
+fn compare(input: &Input, (width, height): (usize, usize)) -> String {
+    let (mut single, mut sized) = (input.warehouse(1, 1), input.warehouse(width, height));
+    for (i, &m) in input.moves().iter().enumerate() {
+        if moved(&mut single, m) != moved(&mut sized, m) {
+            let (_, single_outcome) = single.undo().unwrap();
+            let (_, sized_outcome) = sized.undo().unwrap();
+            return format!(
+                "the robot first moves differently on move {} {}\n\n\
+                 before it, with 1x1 boxes:\n{single}\nwith {width}x{height} boxes:\n{sized}\n\
+                 1x1 {}, {width}x{height} {}\n",
+                i + 1,
+                m.arrow(),
+                describe(&single_outcome),
+                describe(&sized_outcome),
+            );
+        }
+    }
+    "the robot makes every move the same way in both warehouses\n".to_owned()
+}
+
+fn execute(file: &str, input: &str, command: Command) -> Result<String, String> {
+    let input = parse::<Input>(input).map_err(|err| err.report(file, input))?;
+    match command {
+        Command::Replay { size, after } => replay(&input, size, after),
+        Command::Compare { size } => Ok(compare(&input, size)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = fs::read_to_string(&args.file)
+        .map_err(|err| format!("could not read {}: {err}", args.file))
+        .and_then(|input| execute(&args.file, &input, args.command));
+    match result {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
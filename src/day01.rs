@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::{parse::number, ParseError, Solution};
+
+pub struct Lists {
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+fn frequencies(list: &[usize]) -> HashMap<usize, usize> {
+    let mut result: HashMap<usize, usize> = HashMap::new();
+    for &n in list {
+        *result.entry(n).or_insert(0) += 1;
+    }
+    result
+}
+
+impl Solution for Lists {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (left, right) = input
+            .lines()
+            .map(|line| {
+                let (a, b) = line
+                    .split_whitespace()
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::expected("two numbers", line))?;
+                Ok((number(a)?, number(b)?))
+            })
+            .collect::<Result<Vec<(usize, usize)>, ParseError>>()?
+            .into_iter()
+            .unzip();
+        Ok(Self { left, right })
+    }
+
+    fn part1(&self) -> usize {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+        left.sort();
+        right.sort();
+        left.into_iter()
+            .zip(right)
+            .map(|(a, b)| a.abs_diff(b))
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        let frequencies1 = frequencies(&self.left);
+        let frequencies2 = frequencies(&self.right);
+        let mut result = 0;
+        for (k, v) in frequencies1.into_iter() {
+            result += v * k * frequencies2.get(&k).unwrap_or(&0);
+        }
+        result
+    }
+}
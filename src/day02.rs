@@ -0,0 +1,57 @@
+use itertools::Itertools;
+
+use crate::{parse::number, ParseError, Solution};
+
+pub struct Reports(Vec<Vec<usize>>);
+
+fn is_safe_increase((a, b): (&usize, &usize)) -> bool {
+    b.checked_sub(*a)
+        .is_some_and(|diff| (1..=3).contains(&diff))
+}
+
+fn is_safe_decrease((a, b): (&usize, &usize)) -> bool {
+    is_safe_increase((b, a))
+}
+
+fn is_safe(report: &[usize]) -> bool {
+    report.iter().tuple_windows().all(is_safe_increase)
+        || report.iter().tuple_windows().all(is_safe_decrease)
+}
+
+fn without(report: &[usize], i: usize) -> Vec<usize> {
+    let mut clone = report.to_vec();
+    clone.remove(i);
+    clone
+}
+
+fn is_safe_dampened(report: &[usize]) -> bool {
+    is_safe(report) || (0..report.len()).any(|i| is_safe(&without(report, i)))
+}
+
+impl Solution for Reports {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let reports = input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(number::<usize>)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(reports))
+    }
+
+    fn part1(&self) -> usize {
+        self.0.iter().filter(|report| is_safe(report)).count()
+    }
+
+    fn part2(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|report| is_safe_dampened(report))
+            .count()
+    }
+}
@@ -0,0 +1,43 @@
+use regex::Regex;
+
+use crate::{ParseError, Solution};
+
+pub struct Memory(String);
+
+impl Solution for Memory {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self(input.to_owned()))
+    }
+
+    fn part1(&self) -> usize {
+        let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+        regex
+            .captures_iter(&self.0)
+            .map(|capture| {
+                capture[1].parse::<usize>().unwrap() * capture[2].parse::<usize>().unwrap()
+            })
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        let regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+        let mut r#do = true;
+        let mut result = 0;
+        for capture in regex.captures_iter(&self.0) {
+            match &capture[0] {
+                "do()" => r#do = true,
+                "don't()" => r#do = false,
+                _ => {
+                    if r#do {
+                        result += capture[1].parse::<usize>().unwrap()
+                            * capture[2].parse::<usize>().unwrap()
+                    }
+                }
+            }
+        }
+        result
+    }
+}
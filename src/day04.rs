@@ -0,0 +1,66 @@
+use itertools::{izip, Itertools};
+
+use crate::{grid::Grid, ParseError, Solution};
+
+pub struct Input(Grid<char>);
+
+type Window = ((char, char, char), (char, char, char), (char, char, char));
+
+fn is_xmas(xmas: &(char, char, char, char)) -> bool {
+    *xmas == ('X', 'M', 'A', 'S') || *xmas == ('S', 'A', 'M', 'X')
+}
+
+fn count_xmas<'a>(line: impl Iterator<Item = &'a char>) -> usize {
+    line.copied().tuple_windows().filter(is_xmas).count()
+}
+
+fn is_mas(a: char, b: char, c: char) -> bool {
+    a == 'M' && b == 'A' && c == 'S' || a == 'S' && b == 'A' && c == 'M'
+}
+
+fn is_x_mas(((a, _, b), (_, c, _), (d, _, e)): &Window) -> bool {
+    is_mas(*a, *c, *e) && is_mas(*b, *c, *d)
+}
+
+impl Input {
+    fn windows(&self) -> impl Iterator<Item = Window> + '_ {
+        self.0.rows().tuple_windows().flat_map(|(a, b, c)| {
+            izip!(
+                a.iter().copied().tuple_windows(),
+                b.iter().copied().tuple_windows(),
+                c.iter().copied().tuple_windows()
+            )
+        })
+    }
+
+    fn xmas(&self) -> usize {
+        self.0
+            .rows()
+            .map(|row| count_xmas(row.iter()))
+            .sum::<usize>()
+            + self.0.columns().map(count_xmas).sum::<usize>()
+            + self.0.backward_diagonals().map(count_xmas).sum::<usize>()
+            + self.0.forward_diagonals().map(count_xmas).sum::<usize>()
+    }
+
+    fn x_mas(&self) -> usize {
+        self.windows().filter(is_x_mas).count()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |c| "XMAS".contains(c).then_some(c)).map(Self)
+    }
+
+    fn part1(&self) -> usize {
+        self.xmas()
+    }
+
+    fn part2(&self) -> usize {
+        self.x_mas()
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{parse::number, ParseError, Solution};
+
+pub struct Input {
+    rules: HashMap<usize, HashSet<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl Input {
+    fn is_ordered(&self, update: &[usize]) -> bool {
+        update
+            .iter()
+            .tuple_combinations()
+            .all(|(a, b)| !self.rules.get(b).is_some_and(|adj| adj.contains(a)))
+    }
+
+    fn dfs(&self, update: &[usize], sorted: &mut Vec<usize>, page: usize) {
+        if sorted.contains(&page) {
+            return;
+        }
+        for &neighbor in update.iter().filter(|&neighbor| {
+            self.rules
+                .get(&page)
+                .is_some_and(|adj| adj.contains(neighbor))
+        }) {
+            self.dfs(update, sorted, neighbor)
+        }
+        sorted.push(page)
+    }
+
+    fn sort(&self, update: &[usize]) -> usize {
+        let mut sorted = Vec::with_capacity(update.len());
+        for &page in update {
+            self.dfs(update, &mut sorted, page);
+        }
+        sorted[sorted.len() / 2]
+    }
+
+    fn ordered(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|update| self.is_ordered(update))
+            .map(|update| update[update.len() / 2])
+            .sum()
+    }
+
+    fn reordered(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|update| !self.is_ordered(update))
+            .map(|update| self.sort(update))
+            .sum()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let mut rules: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (a, b) = line
+                .split_once("|")
+                .ok_or_else(|| ParseError::expected("a rule", line))?;
+            rules.entry(number(a)?).or_default().insert(number(b)?);
+        }
+        let updates = lines
+            .map(|line| line.split(",").map(number).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules, updates })
+    }
+
+    fn part1(&self) -> usize {
+        self.ordered()
+    }
+
+    fn part2(&self) -> usize {
+        self.reordered()
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    parallel, ParseError, Solution,
+};
+
+pub struct Input {
+    /// whether each position is obstructed
+    map: Grid<bool>,
+    start: Point,
+}
+
+impl Input {
+    fn is_within(&self, position: Point) -> bool {
+        self.map.at(position).is_some()
+    }
+
+    fn is_obstructed(&self, position: Point) -> bool {
+        self.map.at(position) == Some(&true)
+    }
+
+    fn visited(&self) -> usize {
+        let mut direction = Direction::Up;
+        let mut position = self.start;
+        let mut visited = HashSet::new();
+
+        while self.is_within(position) {
+            visited.insert(position);
+            let next = position + direction;
+            if self.is_obstructed(next) {
+                direction = direction.turn_right();
+            } else {
+                position = next
+            }
+        }
+        visited.len()
+    }
+
+    fn causes_loop(&self, new_obstruction: Point) -> bool {
+        if new_obstruction == self.start {
+            return false;
+        }
+
+        let mut direction = Direction::Up;
+        let mut position = self.start;
+        let mut visited = HashSet::new();
+
+        while self.is_within(position) {
+            if !visited.insert((direction, position)) {
+                return true;
+            }
+            let next = position + direction;
+            if self.is_obstructed(next) || next == new_obstruction {
+                direction = direction.turn_right();
+            } else {
+                position = next
+            }
+        }
+        false
+    }
+
+    fn loops(&self) -> usize {
+        let positions = self.map.points().map(Point::from).collect::<Vec<_>>();
+        parallel::map(&positions, |&position| self.causes_loop(position))
+            .into_iter()
+            .filter(|&loops| loops)
+            .count()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, markers) = Grid::parse_with_markers(input, &['^'], |c| match c {
+            '#' => Some(true),
+            '.' | '^' => Some(false),
+            _ => None,
+        })?;
+        let start = markers.unique('^')?.into();
+        Ok(Self { map, start })
+    }
+
+    fn part1(&self) -> usize {
+        self.visited()
+    }
+
+    fn part2(&self) -> usize {
+        self.loops()
+    }
+}
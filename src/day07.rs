@@ -0,0 +1,59 @@
+use crate::{parse::number, ParseError, Solution};
+
+pub struct Equations(Vec<(usize, Vec<usize>)>);
+
+fn concatenate(a: usize, b: usize) -> usize {
+    format!("{a}{b}").parse().unwrap()
+}
+
+fn is_possible_tail(test_value: usize, acc: usize, numbers: &[usize], concat: bool) -> bool {
+    if let [head, tail @ ..] = numbers {
+        is_possible_tail(test_value, acc + head, tail, concat)
+            || is_possible_tail(test_value, acc * head, tail, concat)
+            || concat && is_possible_tail(test_value, concatenate(acc, *head), tail, concat)
+    } else {
+        acc == test_value
+    }
+}
+
+fn is_possible(test_value: usize, numbers: &[usize], concat: bool) -> bool {
+    is_possible_tail(test_value, numbers[0], &numbers[1..], concat)
+}
+
+impl Equations {
+    fn calibration(&self, concat: bool) -> usize {
+        self.0
+            .iter()
+            .filter(|(test_value, numbers)| is_possible(*test_value, numbers, concat))
+            .map(|(test_value, _)| test_value)
+            .sum()
+    }
+}
+
+impl Solution for Equations {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let equations = input
+            .lines()
+            .map(|line| {
+                let (test_value, numbers) = line
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::expected("an equation", line))?;
+                let test_value = number(test_value)?;
+                let numbers = numbers.split(" ").map(number).collect::<Result<_, _>>()?;
+                Ok((test_value, numbers))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self(equations))
+    }
+
+    fn part1(&self) -> usize {
+        self.calibration(false)
+    }
+
+    fn part2(&self) -> usize {
+        self.calibration(true)
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::{iterate, Itertools};
+
+use crate::{
+    geometry::{Point, Vector},
+    grid::Grid,
+    ParseError, Solution,
+};
+
+fn gcd(difference: Vector) -> Vector {
+    // lol turns out gcd wasn't necessary
+    difference
+}
+
+pub struct Input {
+    map: Grid<char>,
+    antennas: HashMap<char, HashSet<Point>>,
+}
+
+impl Input {
+    fn is_within(&self, position: &Point) -> bool {
+        self.map.at(*position).is_some()
+    }
+
+    fn antinodes(&self, frequency: &char) -> impl Iterator<Item = Point> + '_ {
+        self.antennas[frequency]
+            .iter()
+            .permutations(2)
+            .map(|perm| {
+                let [&a, &b] = perm[..] else { unreachable!() };
+                a + (a - b)
+            })
+            .filter(|position| self.is_within(position))
+    }
+
+    fn resonant_antinodes(&self, frequency: &char) -> impl Iterator<Item = Point> + '_ {
+        self.antennas[frequency]
+            .iter()
+            .permutations(2)
+            .flat_map(|perm| {
+                let [&a, &b] = perm[..] else { unreachable!() };
+                let step = gcd(b - a);
+                iterate(a, move |&acc| acc + step).take_while(|position| self.is_within(position))
+            })
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, Some)?;
+        let mut antennas: HashMap<char, HashSet<Point>> = HashMap::new();
+        for (position, &frequency) in map.iter() {
+            if frequency.is_ascii_alphanumeric() {
+                antennas
+                    .entry(frequency)
+                    .or_default()
+                    .insert(position.into());
+            }
+        }
+        Ok(Self { map, antennas })
+    }
+
+    fn part1(&self) -> usize {
+        let antinodes: HashSet<Point> = self
+            .antennas
+            .keys()
+            .flat_map(|frequency| self.antinodes(frequency))
+            .collect();
+        antinodes.len()
+    }
+
+    fn part2(&self) -> usize {
+        let antinodes: HashSet<Point> = self
+            .antennas
+            .keys()
+            .flat_map(|frequency| self.resonant_antinodes(frequency))
+            .collect();
+        antinodes.len()
+    }
+}
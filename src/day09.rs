@@ -0,0 +1,118 @@
+use std::iter::repeat_n;
+
+use itertools::Itertools;
+
+use crate::{ParseError, Solution};
+
+pub struct DiskMap(Vec<usize>);
+
+struct Segment {
+    length: usize,
+    file: Option<usize>,
+}
+
+impl Segment {
+    fn is_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    fn fit(&mut self, size: usize) -> bool {
+        if self.file.is_some() {
+            return false;
+        }
+        let Some(new_length) = self.length.checked_sub(size) else {
+            return false;
+        };
+        self.length = new_length;
+        true
+    }
+
+    fn checksum(&self, i: usize) -> usize {
+        (i..i + self.length).sum::<usize>() * self.file.unwrap_or_default()
+    }
+}
+
+impl Solution for DiskMap {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let disk_map = input.trim_ascii_end();
+        let digits = disk_map
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|d| d as usize)
+                    .ok_or_else(|| ParseError::expected("a digit", &disk_map[i..i + c.len_utf8()]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.is_empty() {
+            return Err(ParseError::new("expected a disk map"));
+        }
+        Ok(Self(digits))
+    }
+
+    fn part1(&self) -> usize {
+        let mut disk = self
+            .0
+            .iter()
+            .chunks(2)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, chunk)| {
+                let (&file, &free) = match chunk.exactly_one() {
+                    Ok(file) => (file, &0),
+                    Err(chunk) => chunk.collect_tuple().unwrap(),
+                };
+                repeat_n(Some(i), file).chain(repeat_n(None, free))
+            })
+            .collect_vec();
+        debug_assert!(disk[disk.len() - 1].is_some());
+        let mut i = 0;
+        while i < disk.len() {
+            while disk.ends_with(&[None]) {
+                disk.pop();
+            }
+            if disk[i].is_none() {
+                disk.swap_remove(i);
+            }
+            i += 1;
+        }
+        disk.into_iter()
+            .map(Option::unwrap)
+            .enumerate()
+            .map(|(i, f)| i * f)
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        let mut disk = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, &length)| Segment {
+                length,
+                file: (i % 2 == 0).then_some(i / 2),
+            })
+            .collect_vec();
+        let mut i = disk.len() - 1;
+        'outer: while i > 0 {
+            if disk[i].is_file() {
+                let length = disk[i].length;
+                for j in 0..i {
+                    if disk[j].fit(length) {
+                        let file = disk[i].file.take();
+                        let segment = Segment { length, file };
+                        disk.insert(j, segment);
+                        continue 'outer;
+                    }
+                }
+            }
+            i -= 1;
+        }
+        let (_, result) = disk.into_iter().fold((0, 0), |(i, result), y| {
+            (i + y.length, result + y.checksum(i))
+        });
+        result
+    }
+}
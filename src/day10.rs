@@ -0,0 +1,69 @@
+use im::OrdSet;
+
+use crate::{
+    grid::{Grid, Index},
+    ParseError, Solution,
+};
+
+type Summits = OrdSet<Index>;
+
+pub struct Input(Grid<usize>);
+
+impl Input {
+    fn uphill(&self, point: Index) -> impl Iterator<Item = Index> + '_ {
+        self.0
+            .neighbours4(point)
+            .filter(move |&next| self.0[next] == self.0[point] + 1)
+    }
+
+    fn score(&self, scores: &mut Grid<Option<Summits>>, point: Index) -> Summits {
+        if scores[point].is_none() {
+            scores[point] = Some(if self.0[point] == 9 {
+                OrdSet::unit(point)
+            } else {
+                OrdSet::unions(self.uphill(point).map(|next| self.score(scores, next)))
+            });
+        }
+        scores[point].clone().unwrap()
+    }
+
+    fn rating(&self, ratings: &mut Grid<Option<usize>>, point: Index) -> usize {
+        if ratings[point].is_none() {
+            ratings[point] = Some(if self.0[point] == 9 {
+                1
+            } else {
+                self.uphill(point)
+                    .map(|next| self.rating(ratings, next))
+                    .sum()
+            });
+        }
+        ratings[point].unwrap()
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = Index> + '_ {
+        self.0.positions(|&height| height == 0)
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as usize)).map(Self)
+    }
+
+    fn part1(&self) -> usize {
+        let mut scores = self.0.map(|_| None);
+        self.trailheads()
+            .map(|point| self.score(&mut scores, point).len())
+            .sum()
+    }
+
+    fn part2(&self) -> usize {
+        let mut ratings = self.0.map(|_| None);
+        self.trailheads()
+            .map(|point| self.rating(&mut ratings, point))
+            .sum()
+    }
+}
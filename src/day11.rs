@@ -1,19 +1,18 @@
-use std::{
-    collections::HashMap,
-    io::{read_to_string, stdin},
-};
+use std::collections::HashMap;
 
-use itertools::Itertools;
+use crate::{parse::number, ParseError, Solution};
 
 type Stone = usize;
 type Stones = Vec<Stone>;
 
+pub struct Arrangement(Stones);
+
 fn change(stone: usize) -> Stones {
     if stone == 0 {
         vec![1]
     } else {
         let n_digits = stone.ilog10() + 1;
-        if n_digits % 2 == 0 {
+        if n_digits.is_multiple_of(2) {
             let half = 10usize.pow(n_digits / 2);
             vec![stone / half, stone % half]
         } else {
@@ -22,6 +21,10 @@ fn change(stone: usize) -> Stones {
     }
 }
 
+fn blink(stones: Stones) -> Stones {
+    stones.into_iter().flat_map(change).collect()
+}
+
 #[derive(Default)]
 struct Memo(HashMap<usize, Vec<usize>>);
 
@@ -45,17 +48,31 @@ impl Memo {
     }
 }
 
-fn main() {
-    let stones = read_to_string(stdin())
-        .unwrap()
-        .split_ascii_whitespace()
-        .map(|stone| stone.parse::<usize>().unwrap())
-        .collect_vec();
-    let mut memo = Memo::default();
-    let mut result = 0;
-    for &stone in &stones {
-        memo.compute(stone, 75);
-        result += memo.get(stone)[75];
+impl Solution for Arrangement {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let stones = input
+            .split_ascii_whitespace()
+            .map(number::<usize>)
+            .collect::<Result<_, _>>()?;
+        Ok(Self(stones))
+    }
+
+    fn part1(&self) -> usize {
+        (0..25)
+            .fold(self.0.clone(), |stones, _| blink(stones))
+            .len()
+    }
+
+    fn part2(&self) -> usize {
+        let mut memo = Memo::default();
+        let mut result = 0;
+        for &stone in &self.0 {
+            memo.compute(stone, 75);
+            result += memo.get(stone)[75];
+        }
+        result
     }
-    println!("{result}");
 }
@@ -0,0 +1,84 @@
+use crate::{
+    geometry::{Direction, Point},
+    grid::{Grid, Index},
+    ParseError, Solution,
+};
+
+pub struct Input(Grid<char>);
+
+impl Input {
+    /// the plots of each region
+    fn regions(&self) -> Vec<Vec<Index>> {
+        let mut seen = self.0.map(|_| false);
+        let mut regions = vec![];
+        for start in self.0.points() {
+            if seen[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut region = vec![start];
+            let mut i = 0;
+            while let Some(&plot) = region.get(i) {
+                for adjacent in self.0.neighbours4(plot) {
+                    if self.0[adjacent] == self.0[plot] && !seen[adjacent] {
+                        seen[adjacent] = true;
+                        region.push(adjacent);
+                    }
+                }
+                i += 1;
+            }
+            regions.push(region);
+        }
+        regions
+    }
+
+    /// the sides of `plot` that need a fence, facing another region or the edge of the map
+    fn fences(&self, plot: Index) -> usize {
+        4 - self
+            .0
+            .neighbours4(plot)
+            .filter(|&adjacent| self.0[adjacent] == self.0[plot])
+            .count()
+    }
+
+    /// the corners of `plot` that are corners of its region too, which has as many sides as
+    /// corners
+    fn corners(&self, plot: Index) -> usize {
+        let same = |point: Point| self.0.at(point) == Some(&self.0[plot]);
+        let plot = Point::from(plot);
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| {
+                let side = direction.turn_right();
+                let (ahead, beside) = (same(plot + direction), same(plot + side));
+                // either the region ends both ways, or it goes both ways around a gap
+                (!ahead && !beside) || (ahead && beside && !same(plot + direction + side))
+            })
+            .count()
+    }
+
+    /// the area of each region times the sum of `sides` over its plots
+    fn price(&self, sides: impl Fn(Index) -> usize) -> usize {
+        self.regions()
+            .iter()
+            .map(|region| region.len() * region.iter().map(|&plot| sides(plot)).sum::<usize>())
+            .sum()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, Some).map(Self)
+    }
+
+    fn part1(&self) -> usize {
+        self.price(|plot| self.fences(plot))
+    }
+
+    fn part2(&self) -> usize {
+        self.price(|plot| self.corners(plot))
+    }
+}
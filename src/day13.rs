@@ -0,0 +1,97 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{parse::number, ParseError, Solution};
+
+const OFFSET: i64 = 10000000000000;
+
+#[derive(Clone, Copy)]
+struct Button {
+    x: i64,
+    y: i64,
+}
+
+impl Button {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        static REGEX: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^Button [AB]: X\+(\d+), Y\+(\d+)$").unwrap());
+        let captures = REGEX
+            .captures(line)
+            .ok_or_else(|| ParseError::expected("a button", line))?;
+        let x = number(&captures[1])?;
+        let y = number(&captures[2])?;
+        Ok(Self { x, y })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Claw {
+    a: Button,
+    b: Button,
+    x: i64,
+    y: i64,
+}
+
+impl Claw {
+    fn parse(machine: &str) -> Result<Self, ParseError> {
+        static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap());
+        let mut lines = machine.lines();
+        let mut next = || {
+            lines
+                .next()
+                .ok_or_else(|| ParseError::expected("two buttons and a prize", machine))
+        };
+        let a = Button::parse(next()?)?;
+        let b = Button::parse(next()?)?;
+        let prize = next()?;
+        let captures = REGEX
+            .captures(prize)
+            .ok_or_else(|| ParseError::expected("a prize", prize))?;
+        let x = number(&captures[1])?;
+        let y = number(&captures[2])?;
+        Ok(Self { a, b, x, y })
+    }
+
+    fn offset(self) -> Self {
+        Self {
+            x: self.x + OFFSET,
+            y: self.y + OFFSET,
+            ..self
+        }
+    }
+
+    fn solve(&self) -> Option<i64> {
+        let b_numerator = self.y * self.a.x - self.a.y * self.x;
+        let b_denominator = self.b.y * self.a.x - self.a.y * self.b.x;
+        (b_denominator != 0 && b_numerator % b_denominator == 0).then_some(())?;
+        let b = b_numerator / b_denominator;
+        let a_numerator = self.x - self.b.x * b;
+        let a_denominator = self.a.x;
+        (a_denominator != 0 && a_numerator % a_denominator == 0).then_some(())?;
+        let a = a_numerator / a_denominator;
+        Some(3 * a + b)
+    }
+}
+
+pub struct Arcade(Vec<Claw>);
+
+impl Solution for Arcade {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let claws = input
+            .split("\n\n")
+            .map(Claw::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(Self(claws))
+    }
+
+    fn part1(&self) -> i64 {
+        self.0.iter().filter_map(Claw::solve).sum()
+    }
+
+    fn part2(&self) -> i64 {
+        self.0.iter().filter_map(|claw| claw.offset().solve()).sum()
+    }
+}
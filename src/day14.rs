@@ -0,0 +1,357 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    parallel,
+    parse::number,
+    render::{Image, Rgb},
+    ParseError, Solution,
+};
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+/// how many times `Measure::Quadrants` cuts the area into quadrants, each time cutting every
+/// block into four
+const QUADRANT_CUTS: u32 = 3;
+
+const FLOOR: Rgb = [16, 24, 32];
+const ROBOT: Rgb = [80, 220, 100];
+
+#[derive(Clone)]
+struct Robot {
+    px: i64,
+    py: i64,
+    vx: i64,
+    vy: i64,
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        static REGEX: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap());
+        let captures = REGEX
+            .captures(line)
+            .ok_or_else(|| ParseError::expected("a robot", line))?;
+        let px = number(&captures[1])?;
+        let py = number(&captures[2])?;
+        let vx = number(&captures[3])?;
+        let vy = number(&captures[4])?;
+        Ok(Self { px, py, vx, vy })
+    }
+}
+
+type Quadrant = (Ordering, Ordering);
+
+impl Robot {
+    fn x_after(&self, seconds: i64, width: i64) -> i64 {
+        (self.px + self.vx * seconds).rem_euclid(width)
+    }
+
+    fn y_after(&self, seconds: i64, height: i64) -> i64 {
+        (self.py + self.vy * seconds).rem_euclid(height)
+    }
+
+    fn quadrant_after(&self, seconds: i64, width: i64, height: i64) -> Quadrant {
+        let x = self.x_after(seconds, width);
+        let y = self.y_after(seconds, height);
+        (x.cmp(&(width / 2)), y.cmp(&(height / 2)))
+    }
+}
+
+/// how to tell the frame with the tree apart from the noise in every other frame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Measure {
+    /// the robots bunch up along each axis, so their x and y vary least
+    #[default]
+    Variance,
+    /// the robots crowd into a few columns and rows, so how they spread over the columns, and
+    /// over the rows, carries the least information
+    AxisEntropy,
+    /// the robots crowd into a few parts of the area, so how they spread over its quadrants,
+    /// cut into quadrants again and again, carries the least information
+    Quadrants,
+    /// the tree is the largest group of robots touching each other
+    Cluster,
+}
+
+impl Measure {
+    /// whether the measure scores each axis on its own, so that one period of each axis is
+    /// enough, rather than every frame
+    fn per_axis(self) -> bool {
+        matches!(self, Self::Variance | Self::AxisEntropy)
+    }
+
+    /// how orderly the robots look along an axis of `size` when they're at `values`, where
+    /// higher is more orderly
+    fn axis_score(self, values: impl Iterator<Item = i64>, size: i64) -> f64 {
+        match self {
+            Self::Variance => -variance(values),
+            Self::AxisEntropy => -entropy(values, size),
+            Self::Quadrants | Self::Cluster => unreachable!("{self:?} scores whole frames"),
+        }
+    }
+}
+
+/// the Shannon entropy of how `values` spread over `0..size`, in bits
+fn entropy(values: impl Iterator<Item = i64>, size: i64) -> f64 {
+    let mut counts = vec![0usize; size as usize];
+    for value in values {
+        counts[value as usize] += 1;
+    }
+    let total = counts.iter().sum::<usize>() as f64;
+    counts
+        .into_iter()
+        .filter(|&count| count > 0)
+        .map(|count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn variance(values: impl Iterator<Item = i64>) -> f64 {
+    let values = values.map(|value| value as f64).collect::<Vec<_>>();
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64
+}
+
+/// how many standard deviations each score is from the mean of them all, so that scores of
+/// different measures can be added up
+fn standardize(scores: &[f64]) -> Vec<f64> {
+    let count = scores.len() as f64;
+    let mean = scores.iter().sum::<f64>() / count;
+    let variance = scores
+        .iter()
+        .map(|score| (score - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    // scores that are all the same tell the frames apart no better than no scores at all
+    let deviation = variance.sqrt().max(f64::MIN_POSITIVE);
+    scores
+        .iter()
+        .map(|score| (score - mean) / deviation)
+        .collect()
+}
+
+/// the index of the first of the highest scores
+fn best(scores: &[f64]) -> Option<usize> {
+    // searching backwards finds the first of several equally high scores
+    (0..scores.len())
+        .rev()
+        .max_by(|&a, &b| scores[a].total_cmp(&scores[b]))
+}
+
+/// the first tick that is `x` modulo `width` and `y` modulo `height`, if there is one
+fn chinese_remainder(x: i64, width: i64, y: i64, height: i64) -> Option<i64> {
+    (0..height)
+        .map(|k| x + k * width)
+        .find(|tick| tick % height == y)
+}
+
+pub struct Robots {
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
+    measures: Vec<Measure>,
+}
+
+impl Robots {
+    /// uses a smaller area than the puzzle's, like the example does
+    pub fn with_area(self, width: i64, height: i64) -> Self {
+        Self {
+            width,
+            height,
+            ..self
+        }
+    }
+
+    /// looks for the tree with `measure` and any `others` instead of the variance alone, scoring
+    /// each frame by all of them together
+    pub fn with_measures(
+        self,
+        measure: Measure,
+        others: impl IntoIterator<Item = Measure>,
+    ) -> Self {
+        let measures = [measure].into_iter().chain(others).collect();
+        Self { measures, ..self }
+    }
+
+    /// the first tick where the robots arrange themselves into a tree, or None if the width and
+    /// height share a factor and the ticks the two axes point to never coincide
+    ///
+    /// each measure's scores are standardized so that they weigh the same, and the frame with
+    /// the highest total wins. x repeats every `width` ticks and y every `height`, so when every
+    /// measure is per axis, each axis is scored over one period on its own and the chinese
+    /// remainder theorem combines the ticks where they look least random. otherwise this is the
+    /// slow fallback that scores all `width * height` frames
+    pub fn tree(&self) -> Option<usize> {
+        let x = |robot: &Robot, tick| robot.x_after(tick, self.width);
+        let y = |robot: &Robot, tick| robot.y_after(tick, self.height);
+        if self.measures.iter().all(|measure| measure.per_axis()) {
+            let x = best(&self.axis_scores(self.width, x))? as i64;
+            let y = best(&self.axis_scores(self.height, y))? as i64;
+            return chinese_remainder(x, self.width, y, self.height).map(|tick| tick as usize);
+        }
+        let ticks = (0..self.width * self.height).collect::<Vec<_>>();
+        let mut totals = vec![0.0; ticks.len()];
+        for &measure in &self.measures {
+            let scores = if measure.per_axis() {
+                let x = standardize(&self.scores(measure, self.width, x));
+                let y = standardize(&self.scores(measure, self.height, y));
+                ticks
+                    .iter()
+                    .map(|&tick| x[(tick % self.width) as usize] + y[(tick % self.height) as usize])
+                    .collect()
+            } else {
+                parallel::map(&ticks, |&tick| match measure {
+                    Measure::Quadrants => -self.quadrant_entropy(tick),
+                    _ => self.largest_cluster(tick) as f64,
+                })
+            };
+            for (total, score) in totals.iter_mut().zip(standardize(&scores)) {
+                *total += score;
+            }
+        }
+        best(&totals)
+    }
+
+    /// how orderly the robots look by `measure` at each tick within one period of an axis of
+    /// `size`, where they're at `coordinate`
+    fn scores(
+        &self,
+        measure: Measure,
+        size: i64,
+        coordinate: impl Fn(&Robot, i64) -> i64,
+    ) -> Vec<f64> {
+        (0..size)
+            .map(|tick| {
+                let values = self.robots.iter().map(|robot| coordinate(robot, tick));
+                measure.axis_score(values, size)
+            })
+            .collect()
+    }
+
+    /// how orderly the robots look by every measure together at each tick within one period
+    /// of an axis
+    fn axis_scores(&self, size: i64, coordinate: impl Fn(&Robot, i64) -> i64) -> Vec<f64> {
+        let mut totals = vec![0.0; size as usize];
+        for &measure in &self.measures {
+            let scores = standardize(&self.scores(measure, size, &coordinate));
+            for (total, score) in totals.iter_mut().zip(scores) {
+                *total += score;
+            }
+        }
+        totals
+    }
+
+    /// the Shannon entropy in bits of how the robots spread over the area after `tick` seconds,
+    /// cut into `2^QUADRANT_CUTS` blocks across and down
+    pub fn quadrant_entropy(&self, tick: i64) -> f64 {
+        let side = 1 << QUADRANT_CUTS;
+        let blocks = self.robots.iter().map(|robot| {
+            let column = robot.x_after(tick, self.width) * side / self.width;
+            let row = robot.y_after(tick, self.height) * side / self.height;
+            row * side + column
+        });
+        entropy(blocks, side * side)
+    }
+
+    /// how many cells the largest group of robots covers after `tick` seconds, where a group is
+    /// connected through robots above, below or beside each other
+    pub fn largest_cluster(&self, tick: i64) -> usize {
+        let (width, height) = (self.width, self.height);
+        let mut occupied = vec![false; (width * height) as usize];
+        let cells = self
+            .robots
+            .iter()
+            .map(|robot| robot.y_after(tick, height) * width + robot.x_after(tick, width))
+            .collect::<Vec<_>>();
+        for &cell in &cells {
+            occupied[cell as usize] = true;
+        }
+        let mut largest = 0;
+        for start in cells {
+            if !occupied[start as usize] {
+                continue;
+            }
+            occupied[start as usize] = false;
+            let mut pending = vec![start];
+            let mut size = 0;
+            while let Some(cell) = pending.pop() {
+                size += 1;
+                let (x, y) = (cell % width, cell / width);
+                for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                    let (x, y) = (x + dx, y + dy);
+                    if (0..width).contains(&x) && (0..height).contains(&y) {
+                        let next = y * width + x;
+                        if occupied[next as usize] {
+                            occupied[next as usize] = false;
+                            pending.push(next);
+                        }
+                    }
+                }
+            }
+            largest = largest.max(size);
+        }
+        largest
+    }
+
+    /// the robots after `tick` seconds as a pixel for each tile
+    pub fn image(&self, tick: i64) -> Image {
+        let mut image = Image::new(self.width as usize, self.height as usize, FLOOR);
+        for robot in &self.robots {
+            let (x, y) = (
+                robot.x_after(tick, self.width),
+                robot.y_after(tick, self.height),
+            );
+            image.set((y as usize, x as usize), ROBOT);
+        }
+        image
+    }
+}
+
+impl Solution for Robots {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let robots = input
+            .lines()
+            .map(|line| line.parse::<Robot>())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            robots,
+            width: WIDTH,
+            height: HEIGHT,
+            measures: vec![Measure::default()],
+        })
+    }
+
+    fn part1(&self) -> usize {
+        let mut quadrants: HashMap<Quadrant, usize> = HashMap::new();
+        for robot in &self.robots {
+            *quadrants
+                .entry(robot.quadrant_after(100, self.width, self.height))
+                .or_default() += 1;
+        }
+        [Ordering::Less, Ordering::Greater]
+            .into_iter()
+            .cartesian_product([Ordering::Less, Ordering::Greater])
+            .map(|quadrant| quadrants.get(&quadrant).copied().unwrap_or(0))
+            .product()
+    }
+
+    fn part2(&self) -> usize {
+        self.tree().expect("the robots never line up")
+    }
+}
@@ -0,0 +1,306 @@
+use std::{fmt::Display, iter};
+
+use itertools::Itertools;
+
+use crate::{
+    geometry::{Direction, Point, Vector},
+    grid::Grid,
+    render::{Image, Rgb},
+    ParseError, Solution,
+};
+
+const FLOOR: Rgb = [16, 24, 32];
+const WALL: Rgb = [60, 60, 72];
+const BOX: Rgb = [200, 140, 60];
+const ROBOT: Rgb = [230, 70, 70];
+
+#[derive(Clone, PartialEq, Eq)]
+enum Cell {
+    Robot,
+    Wall,
+    Box,
+}
+
+impl Cell {
+    fn parse(c: char) -> Option<Option<Self>> {
+        match c {
+            '#' => Some(Some(Self::Wall)),
+            'O' => Some(Some(Self::Box)),
+            '@' => Some(Some(Self::Robot)),
+            '.' => Some(None),
+            _ => None,
+        }
+    }
+}
+
+fn gps(position: Point) -> usize {
+    (position.row * 100 + position.col) as usize
+}
+
+/// a box, covering `width` by `height` tiles from its top left corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Parcel {
+    corner: Point,
+    width: i64,
+    height: i64,
+}
+
+impl Parcel {
+    fn tiles(self) -> impl Iterator<Item = Point> {
+        (0..self.height).flat_map(move |row| {
+            (0..self.width).map(move |col| self.corner + Vector::new(row, col))
+        })
+    }
+
+    /// how the tile at `position` looks, with a wide box drawn as `[]` or `[--]`
+    fn symbol(self, position: Point) -> char {
+        match position.col - self.corner.col {
+            _ if self.width == 1 => 'O',
+            0 => '[',
+            col if col == self.width - 1 => ']',
+            _ => '-',
+        }
+    }
+}
+
+/// what happened when the robot tried to move
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveOutcome {
+    /// the robot moved, pushing the boxes whose top left corners were at these tiles
+    Moved { pushed: Vec<Point> },
+    /// a wall stopped the robot, either right in front of it or behind the boxes it pushed
+    Blocked,
+}
+
+/// the warehouse as the robot moves around it one move at a time, remembering every move so
+/// that they can be undone
+///
+/// each box has an id and may cover any number of tiles, and the robot pushes a box when it or
+/// another box it pushes runs into any of them
+#[derive(Clone)]
+pub struct Warehouse {
+    walls: Grid<bool>,
+    /// the id of the box on each tile
+    tiles: Grid<Option<usize>>,
+    /// the boxes, by id
+    boxes: Vec<Parcel>,
+    robot: Point,
+    history: Vec<(Direction, MoveOutcome)>,
+}
+
+impl Warehouse {
+    pub fn robot(&self) -> Point {
+        self.robot
+    }
+
+    /// the moves made so far and what each did, oldest first
+    pub fn history(&self) -> &[(Direction, MoveOutcome)] {
+        &self.history
+    }
+
+    /// the sum of the GPS coordinates of the boxes, measured to their top left corners
+    pub fn gps(&self) -> usize {
+        self.boxes.iter().map(|parcel| gps(parcel.corner)).sum()
+    }
+
+    /// the warehouse as a pixel for each tile
+    pub fn image(&self) -> Image {
+        let mut image = Image::from_grid(&self.walls, |&wall| if wall { WALL } else { FLOOR });
+        for tile in self.boxes.iter().flat_map(|parcel| parcel.tiles()) {
+            image.set(tile.index().unwrap(), BOX);
+        }
+        image.set(self.robot.index().unwrap(), ROBOT);
+        image
+    }
+
+    fn place(&mut self, id: usize) {
+        for tile in self.boxes[id].tiles() {
+            self.tiles[tile] = Some(id);
+        }
+    }
+
+    /// moves the boxes with ids `ids` one tile in direction `m`
+    fn shift(&mut self, ids: &[usize], m: Direction) {
+        for &id in ids {
+            for tile in self.boxes[id].tiles() {
+                self.tiles[tile] = None;
+            }
+        }
+        for &id in ids {
+            self.boxes[id].corner = self.boxes[id].corner + m;
+            self.place(id);
+        }
+    }
+
+    /// the ids of the boxes that moving the robot in direction `m` would push, or None if a wall
+    /// or the edge of the map is in the way
+    fn pushed(&self, m: Direction) -> Option<Vec<usize>> {
+        let mut pushed = vec![];
+        let mut pending = vec![self.robot + m];
+        while let Some(tile) = pending.pop() {
+            if self.walls.at(tile) != Some(&false) {
+                return None;
+            }
+            if let Some(&Some(id)) = self.tiles.at(tile) {
+                if !pushed.contains(&id) {
+                    pushed.push(id);
+                    pending.extend(self.boxes[id].tiles().map(|tile| tile + m));
+                }
+            }
+        }
+        Some(pushed)
+    }
+
+    /// tries to move the robot one tile in direction `m`
+    pub fn apply(&mut self, m: Direction) -> MoveOutcome {
+        let outcome = match self.pushed(m) {
+            Some(ids) => {
+                let mut pushed = ids.iter().map(|&id| self.boxes[id].corner).collect_vec();
+                pushed.sort();
+                self.shift(&ids, m);
+                self.robot = self.robot + m;
+                MoveOutcome::Moved { pushed }
+            }
+            None => MoveOutcome::Blocked,
+        };
+        self.history.push((m, outcome.clone()));
+        outcome
+    }
+
+    /// takes back the last move, returning it and what it did, or None if there were no moves
+    pub fn undo(&mut self) -> Option<(Direction, MoveOutcome)> {
+        let (m, outcome) = self.history.pop()?;
+        if let MoveOutcome::Moved { pushed } = &outcome {
+            let ids = pushed
+                .iter()
+                .map(|&corner| self.tiles[corner + m].unwrap())
+                .collect_vec();
+            self.shift(&ids, m.reverse());
+            self.robot = self.robot + m.reverse();
+        }
+        Some((m, outcome))
+    }
+}
+
+/// the warehouse drawn as in the puzzle
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, walls) in self.walls.rows().enumerate() {
+            let row: String = (0..walls.len())
+                .map(|col| {
+                    let position = Point::from((row, col));
+                    match self.tiles[position] {
+                        _ if position == self.robot => '@',
+                        Some(id) => self.boxes[id].symbol(position),
+                        None if walls[col] => '#',
+                        None => '.',
+                    }
+                })
+                .collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Input {
+    map: Grid<Option<Cell>>,
+    moves: Vec<Direction>,
+}
+
+impl Input {
+    pub fn moves(&self) -> &[Direction] {
+        &self.moves
+    }
+
+    /// the warehouse before the robot moves, with every tile of the map scaled up to `width` by
+    /// `height` tiles, so that each box covers that many and the robot takes up the top left one
+    ///
+    /// part 1 is 1 by 1 and part 2 is 2 by 1
+    ///
+    /// panics if either is 0
+    pub fn warehouse(&self, width: usize, height: usize) -> Warehouse {
+        assert!(width > 0 && height > 0, "tiles must be at least 1 by 1");
+        let mut warehouse = Warehouse {
+            walls: Grid::new(self.map.width() * width, self.map.height() * height, false),
+            tiles: Grid::new(self.map.width() * width, self.map.height() * height, None),
+            boxes: vec![],
+            robot: Point::default(),
+            history: vec![],
+        };
+        for ((row, col), cell) in self.map.iter() {
+            let corner = Point::from((row * height, col * width));
+            let parcel = Parcel {
+                corner,
+                width: width as i64,
+                height: height as i64,
+            };
+            match cell {
+                Some(Cell::Robot) => warehouse.robot = corner,
+                Some(Cell::Wall) => {
+                    for tile in parcel.tiles() {
+                        warehouse.walls[tile] = true;
+                    }
+                }
+                Some(Cell::Box) => {
+                    warehouse.boxes.push(parcel);
+                    warehouse.place(warehouse.boxes.len() - 1);
+                }
+                None => {}
+            }
+        }
+        warehouse
+    }
+
+    /// the warehouse after every move, with the map scaled up as in [`Input::warehouse`]
+    pub fn replay(&self, width: usize, height: usize) -> Warehouse {
+        let mut warehouse = self.warehouse(width, height);
+        for &m in &self.moves {
+            warehouse.apply(m);
+        }
+        warehouse
+    }
+
+    /// the warehouse before each move and after the last, as a pixel for each tile, with the map
+    /// scaled up as in [`Input::warehouse`]
+    ///
+    /// each frame is only drawn once it's asked for, since a whole input's worth takes a lot of
+    /// memory
+    pub fn frames(&self, width: usize, height: usize) -> impl Iterator<Item = Image> + '_ {
+        let mut warehouse = self.warehouse(width, height);
+        let first = warehouse.image();
+        iter::once(first).chain(self.moves.iter().map(move |&m| {
+            warehouse.apply(m);
+            warehouse.image()
+        }))
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+        let moves = moves
+            .lines()
+            .flat_map(|line| {
+                line.char_indices().map(|(i, c)| {
+                    Direction::from_arrow(c)
+                        .ok_or_else(|| ParseError::expected("a move", &line[i..i + c.len_utf8()]))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let (map, markers) = Grid::parse_with_markers(map, &['@'], Cell::parse)?;
+        markers.unique('@')?;
+        Ok(Self { map, moves })
+    }
+
+    fn part1(&self) -> usize {
+        self.replay(1, 1).gps()
+    }
+
+    fn part2(&self) -> usize {
+        self.replay(2, 1).gps()
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashSet;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    render::{Image, Rgb},
+    search::{Paths, Search},
+    ParseError, Solution,
+};
+
+const WALL: Rgb = [60, 60, 72];
+const FLOOR: Rgb = [16, 24, 32];
+const SEAT: Rgb = [250, 200, 60];
+const START: Rgb = [80, 220, 100];
+const END: Rgb = [230, 70, 70];
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct PositionAndDirection {
+    position: Point,
+    direction: Direction,
+}
+
+/// what each action adds to the score
+#[derive(Debug, Clone, Copy)]
+struct Costs {
+    turn: usize,
+    step: usize,
+    /// whether the reindeer can turn around in one turn rather than two
+    u_turns: bool,
+}
+
+/// one thing the reindeer does on its way through the maze
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Forward,
+    Left,
+    Right,
+    /// turning around in one go, when allowed
+    Around,
+}
+
+impl Move {
+    /// `F`, `L`, `R` or `U`
+    pub fn letter(self) -> char {
+        match self {
+            Self::Forward => 'F',
+            Self::Left => 'L',
+            Self::Right => 'R',
+            Self::Around => 'U',
+        }
+    }
+}
+
+/// whether each tile is free to walk on
+struct Map(Grid<bool>);
+
+impl Map {
+    fn can_go(&self, &position: &Point) -> bool {
+        self.0.at(position) == Some(&true)
+    }
+
+    /// turning in place or stepping forward, along with the score each adds
+    fn neighbors(
+        &self,
+        &PositionAndDirection {
+            position,
+            direction,
+        }: &PositionAndDirection,
+        costs: Costs,
+    ) -> impl Iterator<Item = (PositionAndDirection, usize)> {
+        let turns = [
+            Some(direction.turn_left()),
+            Some(direction.turn_right()),
+            costs.u_turns.then_some(direction.reverse()),
+        ];
+        let turns = turns.into_iter().flatten().map(move |direction| {
+            let turned = PositionAndDirection {
+                position,
+                direction,
+            };
+            (turned, costs.turn)
+        });
+        let gone = position + direction;
+        let step = self.can_go(&gone).then_some((
+            PositionAndDirection {
+                position: gone,
+                direction,
+            },
+            costs.step,
+        ));
+        turns.chain(step)
+    }
+}
+
+pub struct Input {
+    map: Map,
+    start: PositionAndDirection,
+    end: Point,
+    costs: Costs,
+}
+
+impl Input {
+    /// scores each turn `turn` instead of 1000
+    pub fn with_turn_cost(self, turn: usize) -> Self {
+        Self {
+            costs: Costs { turn, ..self.costs },
+            ..self
+        }
+    }
+
+    /// scores each step forward `step` instead of 1
+    pub fn with_step_cost(self, step: usize) -> Self {
+        Self {
+            costs: Costs { step, ..self.costs },
+            ..self
+        }
+    }
+
+    /// lets the reindeer turn around for the score of a single turn, rather than by turning twice
+    pub fn with_u_turns(self, u_turns: bool) -> Self {
+        Self {
+            costs: Costs {
+                u_turns,
+                ..self.costs
+            },
+            ..self
+        }
+    }
+
+    /// finds every best route from the start to the end at once
+    pub fn solve(&self) -> Solved<'_> {
+        let paths = Search::new().with_all_predecessors().dijkstra(
+            [self.start],
+            |state| self.map.neighbors(state, self.costs),
+            |state| state.position == self.end,
+        );
+        let seats = paths
+            .on_cheapest_paths(paths.goals())
+            .into_iter()
+            .map(|state| state.position)
+            .collect();
+        Solved {
+            maze: self,
+            paths,
+            seats,
+        }
+    }
+}
+
+/// the best routes through a maze, which every answer about them reads from
+pub struct Solved<'a> {
+    maze: &'a Input,
+    paths: Paths<PositionAndDirection, usize>,
+    /// the tiles on any of the best routes
+    seats: HashSet<Point>,
+}
+
+impl Solved<'_> {
+    /// the lowest score of any route from the start to the end, or None if there is none
+    pub fn score(&self) -> Option<usize> {
+        self.paths.goal_cost()
+    }
+
+    /// how many tiles are on any of the best routes
+    pub fn seats(&self) -> usize {
+        self.seats.len()
+    }
+
+    /// the moves along one of the best routes from the start to the end, or None if there is none
+    pub fn route(&self) -> Option<Vec<Move>> {
+        let states = self.paths.path(self.paths.goals().first()?)?;
+        let moves = states.windows(2).map(|pair| {
+            let (from, to) = (pair[0], pair[1]);
+            if from.position != to.position {
+                Move::Forward
+            } else if to.direction == from.direction.turn_left() {
+                Move::Left
+            } else if to.direction == from.direction.turn_right() {
+                Move::Right
+            } else {
+                Move::Around
+            }
+        });
+        Some(moves.collect())
+    }
+
+    /// the maze as a pixel for each tile, with the tiles on the best paths lit up
+    pub fn image(&self) -> Image {
+        let maze = self.maze;
+        let mut image = Image::from_grid(&maze.map.0, |&free| if free { FLOOR } else { WALL });
+        for seat in &self.seats {
+            image.set(seat.index().unwrap(), SEAT);
+        }
+        image.set(maze.start.position.index().unwrap(), START);
+        image.set(maze.end.index().unwrap(), END);
+        image
+    }
+
+    /// the maze as in the puzzle, with an `O` on every tile of the best paths other than the
+    /// start and end
+    pub fn picture(&self) -> String {
+        let maze = self.maze;
+        let mut picture = maze.map.0.map(|&free| if free { '.' } else { '#' });
+        for &seat in &self.seats {
+            picture[seat] = 'O';
+        }
+        picture[maze.start.position] = 'S';
+        picture[maze.end] = 'E';
+        picture
+            .rows()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (grid, markers) = Grid::parse_with_markers(input, &['S', 'E'], |c| match c {
+            '#' => Some(false),
+            '.' | 'S' | 'E' => Some(true),
+            _ => None,
+        })?;
+        let start = PositionAndDirection {
+            position: markers.unique('S')?.into(),
+            direction: Direction::Right,
+        };
+        Ok(Self {
+            map: Map(grid),
+            start,
+            end: markers.unique('E')?.into(),
+            costs: Costs {
+                turn: 1000,
+                step: 1,
+                u_turns: false,
+            },
+        })
+    }
+
+    /// panics if there is no way to the end, which [`Solved::score`] reports as None instead
+    fn part1(&self) -> usize {
+        self.solve().score().expect("there is no way to the end")
+    }
+
+    fn part2(&self) -> usize {
+        self.solve().seats()
+    }
+}
@@ -0,0 +1,405 @@
+#[cfg(feature = "z3")]
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::str::FromStr;
+
+use itertools::Itertools;
+#[cfg(feature = "z3")]
+use z3::{
+    ast::{Ast, Bool, BV},
+    Config, Context, Model, Optimize, SatResult, Solver,
+};
+
+#[cfg(feature = "z3")]
+use crate::vm::{Instruction, Opcode, Operand};
+use crate::{
+    vm::{self, Machine, Registers, VmError},
+    ParseError, Solution,
+};
+
+#[derive(Clone)]
+pub struct Program<O, C> {
+    a: O,
+    b: O,
+    c: O,
+    program: Vec<u8>,
+    /// what must hold to reach this point when running symbolically, which only Z3 does
+    #[cfg_attr(not(feature = "z3"), allow(dead_code))]
+    constraints: C,
+}
+
+/// how many instructions a run may take before it counts as an infinite loop
+const STEP_BUDGET: usize = 1 << 20;
+
+/// which starting registers to look for when running a program symbolically: the ones that make
+/// it output exactly `target`, within the allowed range of each register
+#[cfg(feature = "z3")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    target: Vec<u8>,
+    width: u32,
+    a: RangeInclusive<u64>,
+    b: RangeInclusive<u64>,
+    c: RangeInclusive<u64>,
+    step_budget: usize,
+}
+
+#[cfg(feature = "z3")]
+fn context() -> Context {
+    let mut cfg = Config::new();
+    cfg.set_model_generation(true);
+    Context::new(&cfg)
+}
+
+#[cfg(feature = "z3")]
+fn inclusive(range: impl RangeBounds<u64>) -> RangeInclusive<u64> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => Some(end),
+        Bound::Excluded(&end) => end.checked_sub(1),
+        Bound::Unbounded => Some(u64::MAX),
+    };
+    match (start, end) {
+        (Some(start), Some(end)) => start..=end,
+        // nothing fits, say because the range ends before 0
+        _ => RangeInclusive::new(1, 0),
+    }
+}
+
+#[cfg(feature = "z3")]
+impl Query {
+    /// any 64-bit registers that output `target`
+    pub fn new(target: impl Into<Vec<u8>>) -> Self {
+        Self {
+            target: target.into(),
+            width: 64,
+            a: 0..=u64::MAX,
+            b: 0..=u64::MAX,
+            c: 0..=u64::MAX,
+            step_budget: 1000,
+        }
+    }
+
+    /// registers of `width` bits, which must be between 3 and 64
+    pub fn with_width(self, width: u32) -> Self {
+        assert!((3..=64).contains(&width), "invalid register width {width}");
+        Self { width, ..self }
+    }
+
+    pub fn with_a(self, range: impl RangeBounds<u64>) -> Self {
+        Self {
+            a: inclusive(range),
+            ..self
+        }
+    }
+
+    pub fn with_b(self, range: impl RangeBounds<u64>) -> Self {
+        Self {
+            b: inclusive(range),
+            ..self
+        }
+    }
+
+    pub fn with_c(self, range: impl RangeBounds<u64>) -> Self {
+        Self {
+            c: inclusive(range),
+            ..self
+        }
+    }
+
+    /// gives up on paths through the program longer than `budget` instructions, which only
+    /// matters for loops that don't output anything
+    pub fn with_step_budget(self, step_budget: usize) -> Self {
+        Self {
+            step_budget,
+            ..self
+        }
+    }
+}
+
+/// how to find the smallest value of register A for which the program outputs itself
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+    /// builds A three bits at a time, starting from the bits that produce the last output
+    #[default]
+    Search,
+    /// asks Z3 for the smallest A along every path through the program
+    #[cfg(feature = "z3")]
+    Z3,
+}
+
+impl FromStr for Backend {
+    type Err = ParseError;
+
+    /// `search`, or `z3` when built with Z3
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "search" => Ok(Self::Search),
+            #[cfg(feature = "z3")]
+            "z3" => Ok(Self::Z3),
+            #[cfg(not(feature = "z3"))]
+            "z3" => Err(ParseError::new(
+                "the z3 backend needs Z3, so build with --features z3",
+            )),
+            _ => Err(ParseError::expected("search or z3", s)),
+        }
+    }
+}
+
+impl Program<u64, ()> {
+    /// the output when running the program with `a` in register A
+    fn output(&self, a: u64) -> Result<Vec<u8>, VmError> {
+        let registers = Registers {
+            a,
+            b: self.b,
+            c: self.c,
+        };
+        let mut machine = Machine::new(&self.program, registers).with_step_budget(STEP_BUDGET);
+        machine.run()?;
+        Ok(machine.output)
+    }
+
+    /// the smallest value of register A for which the program outputs itself
+    pub fn quine(&self, backend: Backend) -> Option<u64> {
+        match backend {
+            Backend::Search => self.search_quine(0, 0),
+            #[cfg(feature = "z3")]
+            Backend::Z3 => self.z3_quine(),
+        }
+    }
+
+    /// the smallest A that starts with the 3-bit chunks of `prefix` and outputs the program,
+    /// given that `prefix` alone outputs its last `matched` instructions
+    ///
+    /// this relies on the program shifting A right by three bits each time it loops, like every
+    /// puzzle input does, so each extra chunk of A adds one output at the front
+    fn search_quine(&self, prefix: u64, matched: usize) -> Option<u64> {
+        if matched == self.program.len() {
+            return Some(prefix);
+        }
+        let suffix = &self.program[self.program.len() - matched - 1..];
+        (0..8)
+            .map(|chunk| prefix << 3 | chunk)
+            .filter(|&a| a != 0 && self.output(a).is_ok_and(|output| output == suffix))
+            .find_map(|a| self.search_quine(a, matched + 1))
+    }
+
+    #[cfg(feature = "z3")]
+    fn z3_quine(&self) -> Option<u64> {
+        let query = Query::new(self.program.clone())
+            .with_b(self.b..=self.b)
+            .with_c(self.c..=self.c);
+        self.minimum(&query).map(|registers| registers.a)
+    }
+
+    /// the starting registers that answer `query`, with the smallest A, then B, then C
+    #[cfg(feature = "z3")]
+    pub fn minimum(&self, query: &Query) -> Option<Registers> {
+        let ctx = context();
+        let program = Program::symbolic(&ctx, &self.program, query);
+        let start = program.registers();
+        let optimize = Optimize::new(&ctx);
+        for register in &start {
+            optimize.minimize(register);
+        }
+        program
+            .paths(&query.target, query.step_budget)
+            .into_iter()
+            .filter_map(|path| {
+                optimize.push();
+                optimize.assert(&path);
+                let registers = match optimize.check(&[]) {
+                    SatResult::Sat => optimize
+                        .get_model()
+                        .map(|model| Program::evaluate(&model, &start)),
+                    _ => None,
+                };
+                optimize.pop();
+                registers
+            })
+            .min_by_key(|registers| (registers.a, registers.b, registers.c))
+    }
+
+    /// up to `limit` different starting registers that answer `query`, in no particular order
+    #[cfg(feature = "z3")]
+    pub fn solve(&self, query: &Query, limit: usize) -> Vec<Registers> {
+        let ctx = context();
+        let program = Program::symbolic(&ctx, &self.program, query);
+        let start = program.registers();
+        let solver = Solver::new(&ctx);
+        let mut solutions = vec![];
+        // each starting state takes exactly one path, so no solution turns up along two paths
+        for path in program.paths(&query.target, query.step_budget) {
+            solver.push();
+            solver.assert(&path);
+            while solutions.len() < limit && solver.check() == SatResult::Sat {
+                let Some(model) = solver.get_model() else {
+                    break;
+                };
+                let found = Program::evaluate(&model, &start);
+                let differs = [found.a, found.b, found.c]
+                    .into_iter()
+                    .zip(&start)
+                    .map(|(value, register)| {
+                        register._eq(&BV::from_u64(&ctx, value, query.width)).not()
+                    })
+                    .collect::<Vec<_>>();
+                solver.assert(&Bool::or(&ctx, &differs.iter().collect::<Vec<_>>()));
+                solutions.push(found);
+            }
+            solver.pop(1);
+            if solutions.len() >= limit {
+                break;
+            }
+        }
+        solutions
+    }
+}
+
+#[cfg(feature = "z3")]
+impl<'ctx> Program<BV<'ctx>, Bool<'ctx>> {
+    /// the program with unknown starting registers, constrained to the ranges in `query`
+    fn symbolic(ctx: &'ctx Context, program: &[u8], query: &Query) -> Self {
+        let mut symbolic = Self {
+            a: BV::new_const(ctx, "A", query.width),
+            b: BV::new_const(ctx, "B", query.width),
+            c: BV::new_const(ctx, "C", query.width),
+            program: program.to_vec(),
+            constraints: Bool::from_bool(ctx, true),
+        };
+        let within = [&query.a, &query.b, &query.c]
+            .into_iter()
+            .zip(symbolic.registers())
+            .map(|(range, register)| symbolic.within(&register, range))
+            .collect::<Vec<_>>();
+        for constraint in within {
+            symbolic.and(constraint);
+        }
+        symbolic
+    }
+
+    fn ctx(&self) -> &'ctx Context {
+        self.constraints.get_ctx()
+    }
+
+    fn constant(&self, value: u64) -> BV<'ctx> {
+        BV::from_u64(self.ctx(), value, self.a.get_size())
+    }
+
+    fn registers(&self) -> [BV<'ctx>; 3] {
+        [self.a.clone(), self.b.clone(), self.c.clone()]
+    }
+
+    fn evaluate(model: &Model<'ctx>, registers: &[BV<'ctx>; 3]) -> Registers {
+        let [a, b, c] = registers.clone().map(|register| {
+            model
+                .eval(&register, true)
+                .and_then(|value| value.as_u64())
+                .unwrap()
+        });
+        Registers { a, b, c }
+    }
+
+    /// whether `register` is within `range`, which may go past what the register can hold
+    fn within(&self, register: &BV<'ctx>, range: &RangeInclusive<u64>) -> Bool<'ctx> {
+        let size = self.a.get_size();
+        let max = u64::MAX >> (64 - size);
+        if range.is_empty() || *range.start() > max {
+            return Bool::from_bool(self.ctx(), false);
+        }
+        let start = self.constant(*range.start()).bvule(register);
+        let end = register.bvule(&self.constant((*range.end()).min(max)));
+        Bool::and(self.ctx(), &[&start, &end])
+    }
+
+    fn and(&mut self, constraint: Bool<'ctx>) {
+        self.constraints = Bool::and(self.ctx(), &[&self.constraints, &constraint])
+    }
+
+    /// what must hold for each path through the program that outputs exactly `target`, forking
+    /// at every `jnz` and dropping paths that output too much or take more than `step_budget`
+    /// instructions
+    fn paths(self, target: &[u8], step_budget: usize) -> Vec<Bool<'ctx>> {
+        let mut paths = vec![];
+        let mut pending = vec![(self, 0, 0, 0)];
+        'paths: while let Some((mut program, mut ip, mut output, mut steps)) = pending.pop() {
+            while let Some(instruction) = program
+                .program
+                .get(ip..ip + 2)
+                .and_then(|pair| Instruction::decode(pair[0], pair[1]))
+            {
+                if steps == step_budget {
+                    continue 'paths;
+                }
+                steps += 1;
+                ip += 2;
+                let value = match instruction.operand() {
+                    Operand::Literal(literal) => program.constant(literal.into()),
+                    Operand::A => program.a.clone(),
+                    Operand::B => program.b.clone(),
+                    Operand::C => program.c.clone(),
+                    Operand::Ignored(_) => program.constant(0),
+                    Operand::Reserved => continue 'paths,
+                };
+                match instruction.opcode {
+                    Opcode::Adv => program.a = program.a.bvlshr(&value),
+                    Opcode::Bxl => program.b ^= &value,
+                    Opcode::Bst => program.b = value.bvand(&program.constant(7)),
+                    Opcode::Jnz => {
+                        let zero = program.a._eq(&program.constant(0));
+                        let mut jump = program.clone();
+                        jump.and(zero.not());
+                        pending.push((jump, instruction.operand.into(), output, steps));
+                        program.and(zero);
+                    }
+                    Opcode::Bxc => program.b = &program.b ^ &program.c,
+                    Opcode::Out => {
+                        let Some(&expected) = target.get(output) else {
+                            continue 'paths;
+                        };
+                        let out = value.bvand(&program.constant(7));
+                        program.and(out._eq(&program.constant(expected.into())));
+                        output += 1;
+                    }
+                    Opcode::Bdv => program.b = program.a.bvlshr(&value),
+                    Opcode::Cdv => program.c = program.a.bvlshr(&value),
+                }
+            }
+            if output == target.len() {
+                paths.push(program.constraints);
+            }
+        }
+        paths
+    }
+}
+
+impl Solution for Program<u64, ()> {
+    type Part1 = String;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (Registers { a, b, c }, program) = vm::parse(input)?;
+        Ok(Self {
+            a,
+            b,
+            c,
+            program,
+            constraints: (),
+        })
+    }
+
+    fn part1(&self) -> String {
+        self.output(self.a)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .into_iter()
+            .map(|o| o.to_string())
+            .join(",")
+    }
+
+    fn part2(&self) -> u64 {
+        self.quine(Backend::default()).unwrap()
+    }
+}
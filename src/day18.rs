@@ -0,0 +1,99 @@
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, Index},
+    parse::number,
+    search::bfs,
+    ParseError, Solution,
+};
+
+const SIZE: usize = 71;
+const KILO: usize = 1024;
+
+const START: Index = (0, 0);
+
+pub struct Bytes {
+    bytes: Vec<Index>,
+    size: usize,
+    fallen: usize,
+}
+
+impl Bytes {
+    /// uses a smaller memory space and fewer fallen bytes, like the example does
+    pub fn with_size(self, size: usize, fallen: usize) -> Self {
+        Self {
+            size,
+            fallen,
+            ..self
+        }
+    }
+
+    /// the memory space after the first `fallen` bytes, marking corrupted positions
+    fn memory(&self, fallen: usize) -> Grid<bool> {
+        let mut memory = Grid::new(self.size, self.size, false);
+        for &(x, y) in &self.bytes[..fallen] {
+            if let Some(corrupted) = memory.get_mut((y, x)) {
+                *corrupted = true;
+            }
+        }
+        memory
+    }
+
+    fn shortest(&self, fallen: usize) -> Option<usize> {
+        let corrupted = self.memory(fallen);
+        let end = (self.size - 1, self.size - 1);
+        bfs(
+            [START],
+            |&point| corrupted.neighbours4(point).filter(|&adj| !corrupted[adj]),
+            |&point| point == end,
+        )
+        .goal_cost()
+    }
+}
+
+impl Solution for Bytes {
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (x, y) = line
+                    .split(',')
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::expected("x,y", line))?;
+                Ok((number(x)?, number(y)?))
+            })
+            .collect::<Result<_, _>>()
+            .map(|bytes| Self {
+                bytes,
+                size: SIZE,
+                fallen: KILO,
+            })
+    }
+
+    fn part1(&self) -> usize {
+        self.shortest(self.fallen.min(self.bytes.len())).unwrap()
+    }
+
+    fn part2(&self) -> String {
+        let blocks = &self.bytes;
+        // the fewest bytes that cut the path, or one more than there are if none do
+        let mut lo = 0;
+        let mut hi = blocks.len() + 1;
+        while lo != hi {
+            let mid = (lo + hi) >> 1;
+            if self.shortest(mid).is_some() {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        match lo.checked_sub(1).and_then(|i| blocks.get(i)) {
+            Some((x, y)) => format!("{x},{y}"),
+            None if lo == 0 => "blocked before any byte falls".to_owned(),
+            None => "never blocked".to_owned(),
+        }
+    }
+}
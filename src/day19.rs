@@ -0,0 +1,70 @@
+use crate::{parallel, ParseError, Solution};
+
+pub struct Towels {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+fn ways(towels: &[String], design: &str) -> usize {
+    let mut memo = vec![0; design.len() + 1];
+    memo[design.len()] = 1;
+    for i in (0..design.len()).rev() {
+        memo[i] = towels
+            .iter()
+            .map(|towel| {
+                if design[i..].starts_with(towel) {
+                    memo[i + towel.len()]
+                } else {
+                    0
+                }
+            })
+            .sum();
+    }
+    memo[0]
+}
+
+fn is_possible(towels: &[String], design: &str) -> bool {
+    let mut memo = vec![false; design.len() + 1];
+    memo[design.len()] = true;
+    for i in (0..design.len()).rev() {
+        memo[i] = towels
+            .iter()
+            .any(|towel| design[i..].starts_with(towel) && memo[i + towel.len()])
+    }
+    memo[0]
+}
+
+impl Solution for Towels {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let towels = lines
+            .next()
+            .ok_or_else(|| ParseError::new("expected a list of towels"))?
+            .split(", ")
+            .map(String::from)
+            .collect();
+        match lines.next() {
+            Some("") => {}
+            Some(line) => return Err(ParseError::expected("a blank line after the towels", line)),
+            None => return Err(ParseError::new("expected a blank line after the towels")),
+        }
+        let designs = lines.map(String::from).collect();
+        Ok(Self { towels, designs })
+    }
+
+    fn part1(&self) -> usize {
+        parallel::map(&self.designs, |design| is_possible(&self.towels, design))
+            .into_iter()
+            .filter(|&possible| possible)
+            .count()
+    }
+
+    fn part2(&self) -> usize {
+        parallel::map(&self.designs, |design| ways(&self.towels, design))
+            .into_iter()
+            .sum()
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops;
+
+use crate::{
+    geometry::Point,
+    grid::{Grid, Index},
+    parallel,
+    search::{bfs, Paths},
+    ParseError, Solution,
+};
+
+const CHEAT_LENGTH: usize = 20;
+const MINIMUM_IMPROVEMENT: usize = 100;
+
+pub struct Racetrack {
+    /// whether each position is on the track
+    map: Grid<bool>,
+    start: Index,
+    end: Index,
+    minimum_improvement: usize,
+}
+
+impl ops::Index<&Index> for Racetrack {
+    type Output = bool;
+
+    fn index(&self, &position: &Index) -> &Self::Output {
+        &self.map[position]
+    }
+}
+
+impl Racetrack {
+    /// counts cheats saving at least this many picoseconds instead of 100
+    pub fn with_minimum_improvement(self, minimum_improvement: usize) -> Self {
+        Self {
+            minimum_improvement,
+            ..self
+        }
+    }
+
+    fn adjacent(&self, &position: &Index) -> impl Iterator<Item = Index> + '_ {
+        self.map.neighbours4(position)
+    }
+
+    fn short_cheats(&self) -> usize {
+        // each state is a position along with the wall passed through, if any
+        let paths = bfs(
+            [(self.start, None)],
+            |&(position, cheat)| {
+                self.adjacent(&position).filter_map(move |adjacent| {
+                    if self[&adjacent] {
+                        Some((adjacent, cheat))
+                    } else if cheat.is_none() {
+                        Some((adjacent, Some(adjacent)))
+                    } else {
+                        None
+                    }
+                })
+            },
+            |&(position, cheat)| position == self.end && cheat.is_none(),
+        );
+        let picoseconds = paths.goal_cost().unwrap();
+        paths
+            .costs()
+            .iter()
+            .filter(|&(&(position, _), &p)| {
+                position == self.end && p + self.minimum_improvement <= picoseconds
+            })
+            .count()
+    }
+
+    fn shortest_without_cheat(&self, start: &Index) -> Paths<Index, usize> {
+        bfs(
+            [*start],
+            |&position| self.adjacent(&position).filter(|adjacent| self[adjacent]),
+            |_| false,
+        )
+    }
+
+    fn cheatable(&self, &from: &Index) -> impl Iterator<Item = Index> + '_ {
+        let (row, col) = from;
+        (row.saturating_sub(CHEAT_LENGTH)..=(row + CHEAT_LENGTH).min(self.map.height() - 1))
+            .flat_map(move |r| {
+                let radius = CHEAT_LENGTH - row.abs_diff(r);
+                (col.saturating_sub(radius)..=(col + radius).min(self.map.width() - 1))
+                    .map(move |c| (r, c))
+                    .filter(|to| self[to])
+            })
+    }
+
+    fn long_cheats(&self) -> usize {
+        let from_start = self.shortest_without_cheat(&self.start);
+        let to_end = &self.shortest_without_cheat(&self.end);
+        let without_cheat = from_start.cost(&self.end).unwrap();
+        let track = from_start.costs().iter().collect::<Vec<_>>();
+        parallel::map(&track, |&(from, &before)| {
+            self.cheatable(from)
+                .filter(|to| {
+                    to_end.cost(to).is_some_and(|after| {
+                        before
+                            + Point::from(*from).manhattan(Point::from(*to))
+                            + after
+                            + self.minimum_improvement
+                            <= without_cheat
+                    })
+                })
+                .count()
+        })
+        .into_iter()
+        .sum()
+    }
+}
+
+impl Solution for Racetrack {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (map, markers) = Grid::parse_with_markers(input, &['S', 'E'], |c| match c {
+            '#' => Some(false),
+            '.' | 'S' | 'E' => Some(true),
+            _ => None,
+        })?;
+        Ok(Self {
+            map,
+            start: markers.unique('S')?,
+            end: markers.unique('E')?,
+            minimum_improvement: MINIMUM_IMPROVEMENT,
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.short_cheats()
+    }
+
+    fn part2(&self) -> usize {
+        self.long_cheats()
+    }
+}
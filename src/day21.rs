@@ -0,0 +1,336 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    parse::number,
+    ParseError, Solution,
+};
+
+/// the keypad on the door
+pub const NUMERIC: &str = "789\n456\n123\n 0A";
+
+/// the keypad that you and all but the last robot press
+pub const DIRECTIONAL: &str = " ^A\n<v>";
+
+/// a keypad, read from rows of keys with a space for each gap, where no robot may point its arm
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keypad {
+    grid: Grid<Option<char>>,
+    keys: BTreeMap<char, Point>,
+}
+
+impl Keypad {
+    pub fn numeric() -> Self {
+        NUMERIC.parse().unwrap()
+    }
+
+    pub fn directional() -> Self {
+        DIRECTIONAL.parse().unwrap()
+    }
+
+    /// the keys an arm over this keypad presses when driven by `presses` on the keypad below,
+    /// starting at A
+    pub fn drive(&self, presses: &str) -> Result<String, PressError> {
+        let mut arm = self.keys[&'A'];
+        let mut pressed = String::new();
+        for (press, key) in presses.chars().enumerate() {
+            if key == 'A' {
+                pressed.push(self.grid.at(arm).copied().flatten().unwrap());
+                continue;
+            }
+            let direction = Direction::from_arrow(key).ok_or(PressError::Unknown { press, key })?;
+            arm = arm + direction;
+            if !matches!(self.grid.at(arm), Some(Some(_))) {
+                return Err(PressError::Gap { press });
+            }
+        }
+        Ok(pressed)
+    }
+
+    /// every shortest way for an arm to move from `from` to `to` without pointing at a gap, as
+    /// the arrows to press
+    fn moves(&self, from: char, to: char) -> Vec<Vec<char>> {
+        let (Some(&from), Some(&to)) = (self.keys.get(&from), self.keys.get(&to)) else {
+            return vec![];
+        };
+        let mut moves = vec![];
+        self.extend(from, to, &mut vec![], &mut moves);
+        moves
+    }
+
+    fn extend(&self, from: Point, to: Point, path: &mut Vec<char>, moves: &mut Vec<Vec<char>>) {
+        if from == to {
+            moves.push(path.clone());
+            return;
+        }
+        let closer = [
+            (to.row < from.row, Direction::Up),
+            (to.row > from.row, Direction::Down),
+            (to.col < from.col, Direction::Left),
+            (to.col > from.col, Direction::Right),
+        ];
+        for (_, direction) in closer.into_iter().filter(|&(closer, _)| closer) {
+            let next = from + direction;
+            if let Some(Some(_)) = self.grid.at(next) {
+                path.push(direction.arrow());
+                self.extend(next, to, path, moves);
+                path.pop();
+            }
+        }
+    }
+}
+
+impl FromStr for Keypad {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // only newlines are trimmed, since leading spaces are gaps
+        let s = s.trim_matches('\n');
+        let grid = Grid::parse(s, |c| Some((c != ' ').then_some(c)))?;
+        let mut keys = BTreeMap::new();
+        for (row, line) in s.lines().enumerate() {
+            for (col, (i, key)) in line.char_indices().enumerate() {
+                if key == ' ' {
+                    continue;
+                }
+                if keys.insert(key, Point::from((row, col))).is_some() {
+                    return Err(ParseError::expected(
+                        "a key not already on the keypad",
+                        &line[i..i + key.len_utf8()],
+                    ));
+                }
+            }
+        }
+        if !keys.contains_key(&'A') {
+            return Err(ParseError::new("expected a keypad with an A key"));
+        }
+        Ok(Self { grid, keys })
+    }
+}
+
+/// why an arm couldn't follow a sequence of presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressError {
+    /// the press at this index pointed the arm at a gap or off the keypad
+    Gap { press: usize },
+    /// the press at this index is neither an arrow nor A
+    Unknown { press: usize, key: char },
+}
+
+impl Display for PressError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Gap { press } => write!(f, "press {press} points the arm at a gap"),
+            Self::Unknown { press, key } => {
+                write!(f, "press {press} is {key:?}, not an arrow or A")
+            }
+        }
+    }
+}
+
+/// the fewest presses you need to make for the robot on each layer to move its arm from one key
+/// to another and press it
+///
+/// layer 0 is the directional keypad you press yourself, the layers above it up to `robots` are
+/// the directional keypads robots press, and the last is the keypad on the door
+#[derive(Debug, Clone)]
+pub struct Costs {
+    layers: Vec<HashMap<(char, char), usize>>,
+    numeric: Keypad,
+    directional: Keypad,
+}
+
+impl Costs {
+    pub fn new(numeric: &Keypad, directional: &Keypad, robots: usize) -> Self {
+        let pairs = |keypad: &Keypad| {
+            let keys = keypad.keys.keys().copied();
+            keys.clone().cartesian_product(keys).collect_vec()
+        };
+        let mut costs = Self {
+            layers: vec![pairs(directional)
+                .into_iter()
+                .map(|pair| (pair, 1))
+                .collect()],
+            numeric: numeric.clone(),
+            directional: directional.clone(),
+        };
+        for layer in 1..=robots + 1 {
+            let keypad = if layer <= robots {
+                directional
+            } else {
+                numeric
+            };
+            let table = pairs(keypad)
+                .into_iter()
+                .filter_map(|(from, to)| {
+                    let cheapest = keypad
+                        .moves(from, to)
+                        .into_iter()
+                        .filter_map(|mut moves| {
+                            moves.push('A');
+                            costs.presses(layer - 1, moves)
+                        })
+                        .min()?;
+                    Some(((from, to), cheapest))
+                })
+                .collect();
+            costs.layers.push(table);
+        }
+        costs
+    }
+
+    /// the layer of the keypad on the door
+    pub fn door(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// the keypad pressed on `layer`
+    pub fn keypad(&self, layer: usize) -> &Keypad {
+        if layer < self.door() {
+            &self.directional
+        } else {
+            &self.numeric
+        }
+    }
+
+    /// the presses on every layer from yours up to `layer` that make the robot there press
+    /// `keys` the cheapest way, indexed by layer, or None if it can't
+    ///
+    /// each layer takes about two and a half times as many presses as the one above, so this is
+    /// only practical for a handful of robots
+    pub fn sequences(&self, layer: usize, keys: &str) -> Option<Vec<String>> {
+        let mut sequences = vec![keys.to_owned()];
+        for layer in (1..=layer).rev() {
+            let mut below = String::new();
+            let mut from = 'A';
+            for to in sequences.last().unwrap().chars() {
+                let (_, moves) = self
+                    .keypad(layer)
+                    .moves(from, to)
+                    .into_iter()
+                    .filter_map(|mut moves| {
+                        moves.push('A');
+                        Some((self.presses(layer - 1, moves.iter().copied())?, moves))
+                    })
+                    .min_by_key(|&(presses, _)| presses)?;
+                below.extend(moves);
+                from = to;
+            }
+            sequences.push(below);
+        }
+        sequences.reverse();
+        Some(sequences)
+    }
+
+    /// the fewest presses you need to make for the robot on `layer` to press `keys`, starting
+    /// with its arm at A, or None if it can't or there are too many to count
+    pub fn presses(&self, layer: usize, keys: impl IntoIterator<Item = char>) -> Option<usize> {
+        let table = self.layers.get(layer)?;
+        let (_, presses) = keys
+            .into_iter()
+            .try_fold(('A', 0usize), |(from, presses), to| {
+                Some((to, presses.checked_add(*table.get(&(from, to))?)?))
+            })?;
+        Some(presses)
+    }
+}
+
+pub struct Codes {
+    codes: Vec<(String, usize)>,
+    numeric: Keypad,
+    directional: Keypad,
+}
+
+impl Codes {
+    /// types the codes on other keypads, which may be any shape as long as they have an A key
+    pub fn with_keypads(self, numeric: Keypad, directional: Keypad) -> Self {
+        Self {
+            numeric,
+            directional,
+            ..self
+        }
+    }
+
+    /// the sum of the complexities of the codes when `robots` robots press directional keypads
+    /// between you and the robot at the door, or None if the keypads can't type some code or the
+    /// sum is too big to count
+    pub fn complexity(&self, robots: usize) -> Option<usize> {
+        let costs = Costs::new(&self.numeric, &self.directional, robots);
+        self.codes.iter().try_fold(0usize, |sum, (code, number)| {
+            let presses = costs.presses(costs.door(), code.chars())?;
+            sum.checked_add(presses.checked_mul(*number)?)
+        })
+    }
+
+    /// the fewest presses you need to make to type `code` with `robots` robots in between, or
+    /// None if the keypads can't type it
+    pub fn presses(&self, code: &str, robots: usize) -> Option<usize> {
+        let costs = Costs::new(&self.numeric, &self.directional, robots);
+        costs.presses(costs.door(), code.chars())
+    }
+
+    /// the presses on every layer that type `code` the cheapest way, from yours up to the code
+    /// itself, or None if the keypads can't type it
+    ///
+    /// panics if driving the keypads with the presses doesn't type `code`, or points an arm at a
+    /// gap, which would mean the costs are wrong
+    pub fn sequences(&self, code: &str, robots: usize) -> Option<Vec<String>> {
+        let costs = Costs::new(&self.numeric, &self.directional, robots);
+        let sequences = costs.sequences(costs.door(), code)?;
+        for (layer, pair) in sequences.windows(2).enumerate() {
+            let typed = costs
+                .keypad(layer + 1)
+                .drive(&pair[0])
+                .unwrap_or_else(|err| panic!("on layer {layer}, {err}"));
+            assert_eq!(typed, pair[1], "layer {layer} types the wrong keys");
+        }
+        assert_eq!(
+            Some(sequences[0].len()),
+            costs.presses(costs.door(), code.chars()),
+            "the presses cost more than they should"
+        );
+        Some(sequences)
+    }
+}
+
+impl Solution for Codes {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let codes = input
+            .lines()
+            .map(|line| {
+                let digits = line
+                    .strip_suffix('A')
+                    .ok_or_else(|| ParseError::expected("a code ending in A", line))?;
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(ParseError::expected("digits before the A", digits));
+                }
+                Ok((line.to_owned(), number(digits)?))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            codes,
+            numeric: Keypad::numeric(),
+            directional: Keypad::directional(),
+        })
+    }
+
+    fn part1(&self) -> usize {
+        self.complexity(2)
+            .expect("the keypads can't type every code")
+    }
+
+    fn part2(&self) -> usize {
+        self.complexity(25)
+            .expect("the keypads can't type every code")
+    }
+}
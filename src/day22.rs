@@ -1,8 +1,10 @@
-use std::{io::stdin, ops::Add};
+use std::ops::Add;
 
 use im::OrdMap;
 use itertools::Itertools;
 
+use crate::{parallel, parse::number, ParseError, Solution};
+
 fn next(mut x: usize) -> usize {
     x ^= x * 64;
     x %= 16777216;
@@ -13,6 +15,10 @@ fn next(mut x: usize) -> usize {
     x
 }
 
+fn next_2000(x: usize) -> usize {
+    (0..2000).fold(x, |x, _| next(x))
+}
+
 #[derive(Clone)]
 struct Prices(usize);
 
@@ -46,13 +52,26 @@ fn changes(x: usize) -> OrdMap<Changes, usize> {
     result
 }
 
-fn main() {
-    let totals = OrdMap::unions_with(
-        stdin()
+pub struct Buyers(Vec<usize>);
+
+impl Solution for Buyers {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        input
             .lines()
-            .map(|line| changes(line.unwrap().parse().unwrap())),
-        Add::add,
-    );
-    let result = totals.values().max().unwrap();
-    println!("{result}");
+            .map(number)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn part1(&self) -> usize {
+        parallel::map(&self.0, |&x| next_2000(x)).into_iter().sum()
+    }
+
+    fn part2(&self) -> usize {
+        let totals = OrdMap::unions_with(parallel::map(&self.0, |&x| changes(x)), Add::add);
+        *totals.values().max().unwrap()
+    }
 }
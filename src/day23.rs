@@ -0,0 +1,89 @@
+use im::{HashMap, HashSet};
+
+use crate::{ParseError, Solution};
+
+pub struct Graph(HashMap<String, HashSet<String>>);
+
+impl Graph {
+    fn triangles(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(node, _)| node.starts_with("t"))
+            .flat_map(|(node, edges)| {
+                edges.iter().flat_map(|neighbor| {
+                    self.0[neighbor]
+                        .clone()
+                        .intersection(edges.clone())
+                        .into_iter()
+                        .map(|third| {
+                            let mut v = vec![node.clone(), neighbor.clone(), third];
+                            v.sort();
+                            v
+                        })
+                })
+            })
+            .collect::<HashSet<Vec<String>>>()
+            .len()
+    }
+
+    fn clique(&self, node: &str, candidates: &HashSet<String>) -> Vec<String> {
+        if candidates.is_empty() {
+            vec![node.to_owned()]
+        } else {
+            let mut remaining_candidates = candidates.clone();
+            let mut best = vec![];
+            for candidate in candidates {
+                remaining_candidates.remove(candidate).unwrap();
+                let clique = self.clique(candidate, &(&remaining_candidates * &self.0[candidate]));
+                if clique.len() > best.len() {
+                    best = clique;
+                }
+            }
+            best.push(node.to_owned());
+            best
+        }
+    }
+
+    fn maximum_clique(&self) -> Vec<String> {
+        let mut visited = HashSet::new();
+        let mut result = self
+            .0
+            .iter()
+            .map(|(node, edges)| {
+                visited.insert(node.clone());
+                self.clique(node, &edges.clone().relative_complement(visited.clone()))
+            })
+            .max_by_key(|c| c.len())
+            .unwrap();
+        result.sort();
+        result
+    }
+}
+
+impl Solution for Graph {
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut graph: HashMap<String, HashSet<String>> = HashMap::new();
+        for line in input.lines() {
+            let (a, b) = line
+                .split_once("-")
+                .ok_or_else(|| ParseError::expected("a connection", line))?;
+            if a == b {
+                return Err(ParseError::expected("two different computers", line));
+            }
+            graph.entry(a.to_owned()).or_default().insert(b.to_owned());
+            graph.entry(b.to_owned()).or_default().insert(a.to_owned());
+        }
+        Ok(Self(graph))
+    }
+
+    fn part1(&self) -> usize {
+        self.triangles()
+    }
+
+    fn part2(&self) -> String {
+        self.maximum_clique().join(",")
+    }
+}
@@ -0,0 +1,1143 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::{Display, Write},
+    str::FromStr,
+    sync::Arc,
+};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{parallel, parse::number, ParseError, Solution};
+#[cfg(feature = "z3")]
+use z3::{
+    ast::{Ast, BV},
+    Config, Context, SatResult, Solver,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    /// applies the gate to 64 pairs of bits at once
+    fn eval(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        }
+    }
+
+    #[cfg(feature = "z3")]
+    fn symbolic<'ctx>(&self, a: &BV<'ctx>, b: &BV<'ctx>) -> BV<'ctx> {
+        match self {
+            Op::And => a.bvand(b),
+            Op::Or => a.bvor(b),
+            Op::Xor => a.bvxor(b),
+        }
+    }
+
+    /// the Verilog gate primitive, which in upper case is also how the input writes it
+    fn primitive(&self) -> &'static str {
+        match self {
+            Op::And => "and",
+            Op::Or => "or",
+            Op::Xor => "xor",
+        }
+    }
+
+    fn colour(&self) -> &'static str {
+        match self {
+            Op::And => "lightblue",
+            Op::Or => "palegreen",
+            Op::Xor => "lightsalmon",
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Op::And),
+            "OR" => Ok(Op::Or),
+            "XOR" => Ok(Op::Xor),
+            _ => Err(ParseError::expected("a gate", s)),
+        }
+    }
+}
+
+/// the function a circuit should compute from its x and y inputs, as a number on its z outputs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Target {
+    /// x + y, like the puzzle inputs
+    #[default]
+    Add,
+    /// x & y, like the example in part 2
+    And,
+    /// any expression over x and y with `+ - * & | ^ << >> ~`, e.g. `(x ^ y) << 1`
+    Expr(Expr),
+}
+
+impl Target {
+    pub fn eval(&self, x: u64, y: u64) -> u128 {
+        match self {
+            Self::Add => u128::from(x) + u128::from(y),
+            Self::And => u128::from(x & y),
+            Self::Expr(expr) => expr.eval(x, y),
+        }
+    }
+
+    /// the target as a 128-bit vector, given x and y zero-extended to 128 bits
+    #[cfg(feature = "z3")]
+    fn symbolic<'ctx>(&self, x: &BV<'ctx>, y: &BV<'ctx>) -> BV<'ctx> {
+        match self {
+            Self::Add => x.bvadd(y),
+            Self::And => x.bvand(y),
+            Self::Expr(expr) => expr.symbolic(x, y),
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    /// `add`, `and`, or an expression like `(x ^ y) << 1`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "add" => Ok(Self::Add),
+            "and" => Ok(Self::And),
+            _ => s.parse().map(Self::Expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Or,
+    Xor,
+    And,
+    Shl,
+    Shr,
+    Add,
+    Sub,
+    Mul,
+}
+
+impl BinaryOp {
+    /// the operator written as `token`, along with how tightly it binds
+    fn from_token(token: &str) -> Option<(Self, u8)> {
+        Some(match token {
+            "|" => (Self::Or, 1),
+            "^" => (Self::Xor, 2),
+            "&" => (Self::And, 3),
+            "<<" => (Self::Shl, 4),
+            ">>" => (Self::Shr, 4),
+            "+" => (Self::Add, 5),
+            "-" => (Self::Sub, 5),
+            "*" => (Self::Mul, 6),
+            _ => return None,
+        })
+    }
+
+    fn eval(self, a: u128, b: u128) -> u128 {
+        let shift = u32::try_from(b).unwrap_or(u32::MAX);
+        match self {
+            Self::Or => a | b,
+            Self::Xor => a ^ b,
+            Self::And => a & b,
+            Self::Shl => a.checked_shl(shift).unwrap_or(0),
+            Self::Shr => a.checked_shr(shift).unwrap_or(0),
+            Self::Add => a.wrapping_add(b),
+            Self::Sub => a.wrapping_sub(b),
+            Self::Mul => a.wrapping_mul(b),
+        }
+    }
+
+    /// the same as `eval`, since shifting a bit vector by its width or more also gives 0
+    #[cfg(feature = "z3")]
+    fn symbolic<'ctx>(self, a: &BV<'ctx>, b: &BV<'ctx>) -> BV<'ctx> {
+        match self {
+            Self::Or => a.bvor(b),
+            Self::Xor => a.bvxor(b),
+            Self::And => a.bvand(b),
+            Self::Shl => a.bvshl(b),
+            Self::Shr => a.bvlshr(b),
+            Self::Add => a.bvadd(b),
+            Self::Sub => a.bvsub(b),
+            Self::Mul => a.bvmul(b),
+        }
+    }
+}
+
+/// an expression over x and y with C-like operators, evaluated on 128-bit numbers that wrap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    X,
+    Y,
+    Number(u128),
+    Not(Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self, x: u64, y: u64) -> u128 {
+        match self {
+            Self::X => x.into(),
+            Self::Y => y.into(),
+            Self::Number(n) => *n,
+            Self::Not(expr) => !expr.eval(x, y),
+            Self::Binary(a, op, b) => op.eval(a.eval(x, y), b.eval(x, y)),
+        }
+    }
+
+    #[cfg(feature = "z3")]
+    fn symbolic<'ctx>(&self, x: &BV<'ctx>, y: &BV<'ctx>) -> BV<'ctx> {
+        match self {
+            Self::X => x.clone(),
+            Self::Y => y.clone(),
+            Self::Number(n) => {
+                let ctx = x.get_ctx();
+                BV::from_u64(ctx, (n >> 64) as u64, 64).concat(&BV::from_u64(ctx, *n as u64, 64))
+            }
+            Self::Not(expr) => expr.symbolic(x, y).bvnot(),
+            Self::Binary(a, op, b) => op.symbolic(&a.symbolic(x, y), &b.symbolic(x, y)),
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = ExprParser {
+            text: s,
+            position: 0,
+        };
+        let expr = parser.binary(0)?;
+        match parser.peek() {
+            "" => Ok(expr),
+            token => Err(ParseError::expected("an operator", token)),
+        }
+    }
+}
+
+struct ExprParser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> ExprParser<'a> {
+    /// the next token, which is empty at the end of the text
+    fn peek(&self) -> &'a str {
+        let rest = self.text[self.position..].trim_start();
+        let len = if rest.starts_with("<<") || rest.starts_with(">>") {
+            2
+        } else if rest.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+            rest.find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len())
+        } else {
+            rest.chars().next().map_or(0, char::len_utf8)
+        };
+        &rest[..len]
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek();
+        self.position = token.as_ptr() as usize - self.text.as_ptr() as usize + token.len();
+        token
+    }
+
+    /// an expression whose operators all bind at least as tightly as `precedence`
+    fn binary(&mut self, precedence: u8) -> Result<Expr, ParseError> {
+        let mut expr = self.unary()?;
+        while let Some((op, binds)) = BinaryOp::from_token(self.peek()) {
+            if binds < precedence {
+                break;
+            }
+            self.next();
+            expr = Expr::Binary(Box::new(expr), op, Box::new(self.binary(binds + 1)?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        match self.next() {
+            "x" => Ok(Expr::X),
+            "y" => Ok(Expr::Y),
+            "~" => Ok(Expr::Not(Box::new(self.unary()?))),
+            "(" => {
+                let expr = self.binary(0)?;
+                match self.next() {
+                    ")" => Ok(expr),
+                    token => Err(ParseError::expected("a closing parenthesis", token)),
+                }
+            }
+            token if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                Ok(Expr::Number(number(token)?))
+            }
+            token => Err(ParseError::expected(
+                "x, y, a number or a parenthesis",
+                token,
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Circuit {
+    inputs: BTreeMap<String, bool>,
+    gates: BTreeMap<String, (String, Op, String)>,
+    target: Target,
+}
+
+impl Circuit {
+    /// checks the circuit against `target` instead of addition
+    pub fn with_target(self, target: Target) -> Self {
+        Self { target, ..self }
+    }
+
+    /// the circuit compiled for evaluating many inputs at once
+    pub fn simulator(&self) -> Simulator {
+        Simulator::new(self)
+    }
+
+    /// checks the circuit against its target on corner cases and `random` random inputs,
+    /// returning the lowest output bit that's wrong along with an input it's wrong for
+    pub fn test(&self, random: usize) -> Result<(), Mismatch> {
+        let simulator = self.simulator();
+        let batches = simulator.batches(&simulator.pairs(random), &self.target);
+        match simulator.mismatch(&batches, &self.target) {
+            Some(mismatch) => Err(mismatch),
+            None => Ok(()),
+        }
+    }
+
+    /// the fewest swaps of distinct gate outputs that make the circuit compute its target on the
+    /// tested inputs, or None if it takes more than `max_swaps`
+    ///
+    /// every set of one swap is tried, then every set of two, and so on, skipping only sets that
+    /// can't change the lowest wrong output bit. that makes the answer exact, but the search
+    /// grows with the number of gates to the power of the number of swaps, so it's only quick
+    /// for small circuits or few swaps. [`Circuit::local_repair`] handles circuits like the
+    /// puzzle's in a fraction of the time
+    pub fn repair(&self, max_swaps: usize) -> Option<Vec<(String, String)>> {
+        let simulator = self.simulator();
+        let pairs = simulator.pairs(REPAIR_RANDOM_INPUTS);
+        let batches = simulator.batches(&pairs, &self.target);
+        let swaps = (0..=max_swaps).find_map(|swaps| simulator.repair(&batches, swaps))?;
+        Some(simulator.names(&swaps))
+    }
+
+    /// up to `max_swaps` swaps of gate outputs that make the circuit compute its target on the
+    /// tested inputs, found one output bit at a time, or None if that finds none
+    ///
+    /// each swap has to fix the lowest wrong output bit without breaking any below it. when
+    /// every swap is local to one bit, as in the puzzle, that finds the fewest swaps, but
+    /// otherwise it can miss repairs or return more swaps than [`Circuit::repair`]
+    pub fn local_repair(&self, max_swaps: usize) -> Option<Vec<(String, String)>> {
+        let simulator = self.simulator();
+        let pairs = simulator.pairs(REPAIR_RANDOM_INPUTS);
+        let batches = simulator.batches(&pairs, &self.target);
+        let swaps = (0..=max_swaps).find_map(|swaps| simulator.local_repair(&batches, swaps))?;
+        Some(simulator.names(&swaps))
+    }
+
+    /// up to `max_swaps` swaps of gate outputs that make the circuit compute its target on the
+    /// tested inputs, or None if there are none
+    ///
+    /// this tries [`Circuit::local_repair`] first, and [`Circuit::repair`] only if that finds
+    /// nothing, so it finds a repair whenever there is one, quickly for circuits like the
+    /// puzzle's. the local search's answer is the fewest swaps when each swap fixes one output
+    /// bit, as in the puzzle, but may not be otherwise
+    pub fn find_repair(&self, max_swaps: usize) -> Option<Vec<(String, String)>> {
+        let simulator = self.simulator();
+        let pairs = simulator.pairs(REPAIR_RANDOM_INPUTS);
+        let batches = simulator.batches(&pairs, &self.target);
+        let swaps = simulator.find_repair(&batches, max_swaps)?;
+        Some(simulator.names(&swaps))
+    }
+
+    /// the circuit with the outputs of each pair of gates swapped, or None if a wire in the
+    /// pairs isn't the output of a gate
+    pub fn with_swaps(mut self, swaps: &[(String, String)]) -> Option<Self> {
+        for (a, b) in swaps {
+            let gate_a = self.gates.remove(a)?;
+            let gate_b = self.gates.insert(b.clone(), gate_a)?;
+            self.gates.insert(a.clone(), gate_b);
+        }
+        Some(self)
+    }
+
+    /// proves that the circuit computes its target for every x and y, or returns the lowest
+    /// output bit that can be wrong along with an input it's wrong for
+    #[cfg(feature = "z3")]
+    pub fn prove(&self) -> Result<(), Mismatch> {
+        match self.simulator().prove(&self.target) {
+            Some(mismatch) => Err(mismatch),
+            None => Ok(()),
+        }
+    }
+
+    /// up to `max_swaps` swaps that make the circuit compute its target, proven right for every
+    /// input rather than just the ones tested
+    ///
+    /// the search is the same as [`Circuit::find_repair`], but each answer goes to Z3, and any
+    /// input it turns out to be wrong for joins the tests before searching again
+    #[cfg(feature = "z3")]
+    pub fn proven_repair(&self, max_swaps: usize) -> Option<Vec<(String, String)>> {
+        let simulator = self.simulator();
+        let mut pairs = simulator.pairs(REPAIR_RANDOM_INPUTS);
+        loop {
+            let batches = simulator.batches(&pairs, &self.target);
+            let swaps = simulator.find_repair(&batches, max_swaps)?;
+            let mut repaired = simulator.clone();
+            for &(a, b) in &swaps {
+                repaired.swap(a, b);
+            }
+            match repaired.prove(&self.target) {
+                // the repair gets every tested input right, so this one is new
+                Some(mismatch) => pairs.push((mismatch.x, mismatch.y)),
+                None => return Some(simulator.names(&swaps)),
+            }
+        }
+    }
+
+    /// the wires that look miswired: the ones the repair swaps, or the wrong outputs if no swaps
+    /// repair the circuit
+    pub fn suspects(&self) -> BTreeSet<String> {
+        if let Some(swaps) = self.local_repair(PUZZLE_SWAPS) {
+            return swaps.into_iter().flat_map(|(a, b)| [a, b]).collect();
+        }
+        let simulator = self.simulator();
+        let pairs = simulator.pairs(REPAIR_RANDOM_INPUTS);
+        let batches = simulator.batches(&pairs, &self.target);
+        let wrong = simulator.wrong_bits(&batches, simulator.z.len(), false);
+        (0..simulator.z.len())
+            .filter(|&i| wrong >> i & 1 == 1)
+            .map(|i| simulator.names[simulator.z[i]].clone())
+            .collect()
+    }
+
+    fn outputs(&self) -> impl Iterator<Item = &String> {
+        self.gates.keys().filter(|wire| wire.starts_with('z'))
+    }
+
+    /// the circuit as a Graphviz graph with a node for each gate, coloured by its operation, and
+    /// an edge for each wire, with `suspects` in red
+    pub fn to_dot(&self, suspects: &BTreeSet<String>) -> String {
+        let highlight = |wire: &str| {
+            if suspects.contains(wire) {
+                ", color=red, fontcolor=red, penwidth=2"
+            } else {
+                ""
+            }
+        };
+        let mut dot = "digraph circuit {\n    rankdir=LR;\n".to_owned();
+        for wire in self.inputs.keys() {
+            writeln!(dot, "    \"{wire}\" [shape=circle];").unwrap();
+        }
+        for (wire, (_, op, _)) in &self.gates {
+            writeln!(
+                dot,
+                "    \"{wire}\" [label=\"{}\", shape=box, style=filled, fillcolor={}{}];",
+                op.primitive().to_uppercase(),
+                op.colour(),
+                highlight(wire)
+            )
+            .unwrap();
+        }
+        for wire in self.outputs() {
+            writeln!(
+                dot,
+                "    \"{wire} out\" [label=\"{wire}\", shape=doublecircle];"
+            )
+            .unwrap();
+            writeln!(
+                dot,
+                "    \"{wire}\" -> \"{wire} out\" [label=\"{wire}\"{}];",
+                highlight(wire)
+            )
+            .unwrap();
+        }
+        for (wire, (a, _, b)) in &self.gates {
+            for input in [a, b] {
+                writeln!(
+                    dot,
+                    "    \"{input}\" -> \"{wire}\" [label=\"{input}\"{}];",
+                    highlight(input)
+                )
+                .unwrap();
+            }
+        }
+        dot + "}\n"
+    }
+
+    /// the circuit as a structural Verilog module with x, y and z as buses, built from gate
+    /// primitives
+    pub fn to_verilog(&self, module: &str) -> String {
+        // only the parsed inputs and the numbered outputs are ports, so a gate named like `x70`
+        // stays internal, and internal wires get a prefix so names like `and` can't clash with
+        // keywords
+        let buses: HashMap<&str, (&str, usize)> = self
+            .inputs
+            .keys()
+            .chain(self.outputs())
+            .filter_map(|wire| {
+                let (bus, bit) = wire.split_at(1);
+                let bit = bit
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())
+                    .then(|| bit.parse().ok())??;
+                Some((wire.as_str(), (bus, bit)))
+            })
+            .collect();
+        let net = |wire: &str| match buses.get(wire) {
+            Some((bus, bit)) => format!("{bus}[{bit}]"),
+            None => format!("w_{wire}"),
+        };
+        let bits = |bus: &str| {
+            buses
+                .values()
+                .filter(|&&(name, _)| name == bus)
+                .map(|&(_, bit)| bit + 1)
+                .max()
+        };
+        let ports = [("input", "x"), ("input", "y"), ("output", "z")]
+            .into_iter()
+            .filter_map(|(direction, bus)| {
+                Some(format!("    {direction} wire [{}:0] {bus}", bits(bus)? - 1))
+            })
+            .join(",\n");
+        let mut verilog = format!("module {module} (\n{ports}\n);\n");
+        for wire in self
+            .gates
+            .keys()
+            .filter(|wire| !buses.contains_key(wire.as_str()))
+        {
+            writeln!(verilog, "    wire {};", net(wire)).unwrap();
+        }
+        for (wire, (a, op, b)) in &self.gates {
+            writeln!(
+                verilog,
+                "    {} g_{wire} ({}, {}, {});",
+                op.primitive(),
+                net(wire),
+                net(a),
+                net(b)
+            )
+            .unwrap();
+        }
+        verilog + "endmodule\n"
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Driver {
+    X(usize),
+    Y(usize),
+    Gate(usize, Op, usize),
+}
+
+/// how many random inputs the repair tests each swap with, on top of the corner cases
+const REPAIR_RANDOM_INPUTS: usize = 256;
+
+/// how many pairs of gate outputs the puzzle swaps
+const PUZZLE_SWAPS: usize = 4;
+
+/// up to 64 inputs packed into lanes, so that bit `k` of `x[i]` is bit `i` of x in the `k`th
+/// input, and likewise for y and the outputs the target expects
+#[derive(Debug, Clone)]
+struct Batch {
+    pairs: Vec<(u64, u64)>,
+    x: [u64; 64],
+    y: [u64; 64],
+    expected: Vec<u64>,
+    /// the lanes in use
+    active: u64,
+}
+
+impl Batch {
+    fn new(pairs: &[(u64, u64)], expected: impl Fn(u64, u64) -> u128, bits: usize) -> Self {
+        let mut batch = Self {
+            pairs: pairs.to_vec(),
+            x: [0; 64],
+            y: [0; 64],
+            expected: vec![0; bits],
+            active: u64::MAX >> (64 - pairs.len()),
+        };
+        for (k, &(x, y)) in pairs.iter().enumerate() {
+            let z = expected(x, y);
+            for i in 0..64 {
+                batch.x[i] |= (x >> i & 1) << k;
+                batch.y[i] |= (y >> i & 1) << k;
+            }
+            for (i, lane) in batch.expected.iter_mut().enumerate() {
+                *lane |= ((z >> i & 1) as u64) << k;
+            }
+        }
+        batch
+    }
+}
+
+/// the lowest output bit where a circuit disagrees with its target, and an input it disagrees on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub bit: usize,
+    pub x: u64,
+    pub y: u64,
+    pub expected: u128,
+    /// what the circuit outputs, or None if some output depends on a loop
+    pub found: Option<u128>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "z{:02} is wrong for x = {}, y = {}: ",
+            self.bit, self.x, self.y
+        )?;
+        match self.found {
+            Some(found) => write!(f, "expected {}, found {found}", self.expected),
+            None => write!(f, "it depends on a loop"),
+        }
+    }
+}
+
+/// a circuit compiled for simulation, with its gates sorted so that each comes after its inputs
+/// and 64 inputs evaluated at once, one in each bit of a `u64`
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    /// shared between copies, since the repair copies the simulator for every swap it tries
+    names: Arc<[String]>,
+    drivers: Vec<Driver>,
+    /// the wire of each output bit
+    z: Vec<usize>,
+    /// the gates that the outputs depend on, in an order that evaluates their inputs first
+    order: Vec<usize>,
+    /// how many of the lowest outputs `order` covers, stopping before any that depends on a loop
+    acyclic: usize,
+    /// the wires swapped so far, which a repair can't swap again
+    swapped: Vec<usize>,
+}
+
+/// the next number from a fixed sequence that looks random, so tests don't depend on the run
+fn splitmix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+impl Simulator {
+    fn new(circuit: &Circuit) -> Self {
+        let names = circuit
+            .inputs
+            .keys()
+            .chain(circuit.gates.keys())
+            .cloned()
+            .collect::<Vec<_>>();
+        let indices = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let drivers = names
+            .iter()
+            .map(|name| match circuit.gates.get(name) {
+                Some((a, op, b)) => Driver::Gate(indices[a.as_str()], *op, indices[b.as_str()]),
+                None if name.starts_with('x') => Driver::X(name[1..].parse().unwrap()),
+                None => Driver::Y(name[1..].parse().unwrap()),
+            })
+            .collect();
+        let z = circuit
+            .outputs()
+            .map(|wire| indices[wire.as_str()])
+            .collect();
+        let mut simulator = Self {
+            names: names.into(),
+            drivers,
+            z,
+            order: vec![],
+            acyclic: 0,
+            swapped: vec![],
+        };
+        simulator.sort();
+        simulator
+    }
+
+    /// adds `wire` and the gates it depends on to `order`, returning false if it hits a loop
+    fn visit(&self, wire: usize, visited: &mut [u8], order: &mut Vec<usize>) -> bool {
+        match visited[wire] {
+            1 => return false,
+            2 => return true,
+            _ => {}
+        }
+        if let Driver::Gate(a, _, b) = self.drivers[wire] {
+            visited[wire] = 1;
+            if !(self.visit(a, visited, order) && self.visit(b, visited, order)) {
+                return false;
+            }
+            order.push(wire);
+        }
+        visited[wire] = 2;
+        true
+    }
+
+    fn sort(&mut self) {
+        let mut visited = vec![0; self.drivers.len()];
+        let mut order = vec![];
+        self.acyclic = self
+            .z
+            .iter()
+            .take_while(|&&z| self.visit(z, &mut visited, &mut order))
+            .count();
+        self.order = order;
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.drivers.swap(a, b);
+        self.swapped.extend([a, b]);
+        self.sort();
+    }
+
+    /// the outputs as lanes, up to the first one that depends on a loop
+    fn lanes(&self, x: &[u64; 64], y: &[u64; 64]) -> Vec<u64> {
+        let mut values = vec![0; self.drivers.len()];
+        for (wire, driver) in self.drivers.iter().enumerate() {
+            match *driver {
+                Driver::X(i) => values[wire] = x[i],
+                Driver::Y(i) => values[wire] = y[i],
+                Driver::Gate(..) => {}
+            }
+        }
+        for &wire in &self.order {
+            if let Driver::Gate(a, op, b) = self.drivers[wire] {
+                values[wire] = op.eval(values[a], values[b]);
+            }
+        }
+        self.z[..self.acyclic].iter().map(|&z| values[z]).collect()
+    }
+
+    /// the output for each pair of x and y, or None if some output depends on a loop
+    pub fn run(&self, pairs: &[(u64, u64)]) -> Option<Vec<u128>> {
+        if self.acyclic < self.z.len() {
+            return None;
+        }
+        let outputs = pairs.chunks(64).flat_map(|pairs| {
+            let batch = Batch::new(pairs, |_, _| 0, 0);
+            let z = self.lanes(&batch.x, &batch.y);
+            (0..pairs.len()).map(move |k| {
+                z.iter().enumerate().fold(0, |output, (i, lane)| {
+                    output | u128::from(lane >> k & 1) << i
+                })
+            })
+        });
+        Some(outputs.collect())
+    }
+
+    /// how many bits of x and y the circuit reads, up to the highest it has an input for
+    fn input_bits(&self) -> (u32, u32) {
+        let bits = |input: fn(Driver) -> Option<usize>| {
+            let highest = self
+                .drivers
+                .iter()
+                .filter_map(|&driver| input(driver))
+                .max();
+            highest.map_or(0, |bit| bit as u32 + 1)
+        };
+        let x_bits = bits(|driver| match driver {
+            Driver::X(i) => Some(i),
+            _ => None,
+        });
+        let y_bits = bits(|driver| match driver {
+            Driver::Y(i) => Some(i),
+            _ => None,
+        });
+        (x_bits, y_bits)
+    }
+
+    /// corner cases for carries and single bits, then `random` random inputs
+    fn pairs(&self, random: usize) -> Vec<(u64, u64)> {
+        let (x_bits, y_bits) = self.input_bits();
+        let x_mask = u64::MAX.checked_shr(64 - x_bits).unwrap_or(0);
+        let y_mask = u64::MAX.checked_shr(64 - y_bits).unwrap_or(0);
+        let mut pairs = vec![(0, 0), (u64::MAX, u64::MAX)];
+        for i in 0..64 {
+            let bit = 1 << i;
+            pairs.extend([(bit, 0), (0, bit), (bit, bit), (bit - 1, 1), (1, bit - 1)]);
+            pairs.extend([(u64::MAX, bit), (bit, u64::MAX)]);
+        }
+        let mut state = 0;
+        pairs.extend((0..random).map(|_| (splitmix(&mut state), splitmix(&mut state))));
+        pairs
+            .into_iter()
+            .map(|(x, y)| (x & x_mask, y & y_mask))
+            .unique()
+            .collect()
+    }
+
+    fn batches(&self, pairs: &[(u64, u64)], target: &Target) -> Vec<Batch> {
+        let mask = u128::MAX
+            .checked_shr(128 - self.z.len() as u32)
+            .unwrap_or(0);
+        pairs
+            .chunks(64)
+            .map(|pairs| Batch::new(pairs, |x, y| target.eval(x, y) & mask, self.z.len()))
+            .collect()
+    }
+
+    /// which of the lowest `bits` outputs are wrong or depend on a loop, stopping at the first
+    /// batch with anything wrong if `first` is set
+    fn wrong_bits(&self, batches: &[Batch], bits: usize, first: bool) -> u128 {
+        let bits = bits.min(self.z.len());
+        let mut wrong = (self.acyclic..bits).fold(0, |wrong, i| wrong | 1 << i);
+        for batch in batches {
+            if first && wrong != 0 {
+                break;
+            }
+            let z = self.lanes(&batch.x, &batch.y);
+            for (i, lane) in z.iter().enumerate().take(bits) {
+                if (lane ^ batch.expected[i]) & batch.active != 0 {
+                    wrong |= 1 << i;
+                }
+            }
+        }
+        wrong
+    }
+
+    /// the lowest output that disagrees with `target` on any of the batches
+    fn mismatch(&self, batches: &[Batch], target: &Target) -> Option<Mismatch> {
+        let bit = self
+            .wrong_bits(batches, self.z.len(), false)
+            .trailing_zeros() as usize;
+        let (x, y) = if bit >= self.z.len() {
+            return None;
+        } else if bit >= self.acyclic {
+            batches[0].pairs[0]
+        } else {
+            batches.iter().find_map(|batch| {
+                let lane = self.lanes(&batch.x, &batch.y)[bit];
+                let differs = (lane ^ batch.expected[bit]) & batch.active;
+                (differs != 0).then(|| batch.pairs[differs.trailing_zeros() as usize])
+            })?
+        };
+        Some(self.mismatch_at(bit, x, y, target))
+    }
+
+    fn mismatch_at(&self, bit: usize, x: u64, y: u64, target: &Target) -> Mismatch {
+        let mask = u128::MAX
+            .checked_shr(128 - self.z.len() as u32)
+            .unwrap_or(0);
+        Mismatch {
+            bit,
+            x,
+            y,
+            expected: target.eval(x, y) & mask,
+            found: self.run(&[(x, y)]).map(|outputs| outputs[0]),
+        }
+    }
+
+    /// the lowest output that disagrees with `target` for any input, found by handing the
+    /// circuit to Z3 one bit at a time, without quantifiers
+    #[cfg(feature = "z3")]
+    fn prove(&self, target: &Target) -> Option<Mismatch> {
+        let mut cfg = Config::new();
+        cfg.set_model_generation(true);
+        let ctx = Context::new(&cfg);
+        let solver = Solver::new(&ctx);
+        let x = BV::new_const(&ctx, "x", 64);
+        let y = BV::new_const(&ctx, "y", 64);
+        let (x_bits, y_bits) = self.input_bits();
+        // inputs the circuit has no wire for are 0, as when testing it
+        for (input, bits) in [(&x, x_bits), (&y, y_bits)] {
+            if bits < 64 {
+                solver.assert(
+                    &input
+                        .extract(63, bits)
+                        ._eq(&BV::from_u64(&ctx, 0, 64 - bits)),
+                );
+            }
+        }
+        let expected = target.symbolic(&x.zero_ext(64), &y.zero_ext(64));
+        let mut values = self
+            .drivers
+            .iter()
+            .map(|&driver| match driver {
+                Driver::X(i) => Some(x.extract(i as u32, i as u32)),
+                Driver::Y(i) => Some(y.extract(i as u32, i as u32)),
+                Driver::Gate(..) => None,
+            })
+            .collect::<Vec<_>>();
+        for &wire in &self.order {
+            if let Driver::Gate(a, op, b) = self.drivers[wire] {
+                // the order puts the inputs of each gate before it
+                let value = op.symbolic(values[a].as_ref().unwrap(), values[b].as_ref().unwrap());
+                values[wire] = Some(value);
+            }
+        }
+        for (bit, &z) in self.z[..self.acyclic].iter().enumerate() {
+            let right = values[z]
+                .as_ref()
+                .unwrap()
+                ._eq(&expected.extract(bit as u32, bit as u32));
+            solver.push();
+            solver.assert(&right.not());
+            let counterexample = match solver.check() {
+                SatResult::Unsat => None,
+                SatResult::Sat => {
+                    let model = solver.get_model().unwrap();
+                    let value = |input: &BV| model.eval(input, true).unwrap().as_u64().unwrap();
+                    Some((value(&x), value(&y)))
+                }
+                SatResult::Unknown => panic!("Z3 could not decide whether z{bit:02} is right"),
+            };
+            solver.pop(1);
+            if let Some((x, y)) = counterexample {
+                return Some(self.mismatch_at(bit, x, y, target));
+            }
+        }
+        (self.acyclic < self.z.len()).then(|| self.mismatch_at(self.acyclic, 0, 0, target))
+    }
+
+    /// the names of the wires in each swap
+    fn names(&self, swaps: &[(usize, usize)]) -> Vec<(String, String)> {
+        swaps
+            .iter()
+            .map(|&(a, b)| (self.names[a].clone(), self.names[b].clone()))
+            .collect()
+    }
+
+    /// the gate outputs that `wire` depends on, including itself
+    fn cone(&self, wire: usize) -> BTreeSet<usize> {
+        let mut cone = BTreeSet::new();
+        let mut pending = vec![wire];
+        while let Some(wire) = pending.pop() {
+            if let Driver::Gate(a, _, b) = self.drivers[wire] {
+                if cone.insert(wire) {
+                    pending.extend([a, b]);
+                }
+            }
+        }
+        cone
+    }
+
+    /// whether no swap so far has touched `wire`
+    fn unswapped(&self, wire: usize) -> bool {
+        !self.swapped.contains(&wire)
+    }
+
+    /// at most `swaps` swaps that make every output bit right, out of every set that could
+    fn repair(&self, batches: &[Batch], swaps: usize) -> Option<Vec<(usize, usize)>> {
+        if swaps == 0 {
+            return (self.wrong_bits(batches, self.z.len(), true) == 0).then(Vec::new);
+        }
+        let wrong = self.wrong_bits(batches, self.z.len(), false);
+        if wrong == 0 {
+            return Some(vec![]);
+        }
+        // swaps that touch none of the gates an output depends on leave it as it is, so some
+        // swap has to touch the lowest wrong bit's. bits past the first loop are all counted as
+        // wrong, so only those before it, and the one with the loop, are known to be wrong
+        let lowest = wrong.trailing_zeros() as usize;
+        let cones = &(lowest..self.acyclic.max(lowest + 1))
+            .filter(|&i| wrong >> i & 1 == 1)
+            .map(|i| self.cone(self.z[i]))
+            .collect_vec();
+        let gates = (0..self.drivers.len())
+            .filter(|&wire| matches!(self.drivers[wire], Driver::Gate(..)))
+            .collect_vec();
+        let candidates = cones[0]
+            .iter()
+            .flat_map(|&a| {
+                gates
+                    .iter()
+                    // the last swap has to touch every wrong bit's gates on its own
+                    .filter(move |&&b| {
+                        swaps > 1
+                            || cones
+                                .iter()
+                                .all(|cone| cone.contains(&a) || cone.contains(&b))
+                    })
+                    .map(move |&b| (a.min(b), a.max(b)))
+            })
+            .filter(|&(a, b)| a != b && self.unswapped(a) && self.unswapped(b))
+            .unique()
+            .collect_vec();
+        // search below every swap at once, but keep the first repair for a stable answer
+        let repairs = parallel::map(&candidates, |&(a, b)| {
+            let mut simulator = self.clone();
+            simulator.swap(a, b);
+            let mut repaired = simulator.repair(batches, swaps - 1)?;
+            repaired.push((a, b));
+            Some(repaired)
+        });
+        repairs.into_iter().flatten().next()
+    }
+
+    /// at most `max_swaps` swaps that make every output bit right, from the local search if it
+    /// finds any and the exact one otherwise
+    fn find_repair(&self, batches: &[Batch], max_swaps: usize) -> Option<Vec<(usize, usize)>> {
+        (0..=max_swaps)
+            .find_map(|swaps| self.local_repair(batches, swaps))
+            .or_else(|| (0..=max_swaps).find_map(|swaps| self.repair(batches, swaps)))
+    }
+
+    /// at most `swaps` swaps that make every output bit right, each fixing the lowest wrong bit
+    fn local_repair(&self, batches: &[Batch], swaps: usize) -> Option<Vec<(usize, usize)>> {
+        let wrong = self.wrong_bits(batches, self.z.len(), false);
+        if wrong == 0 {
+            return Some(vec![]);
+        }
+        if swaps == 0 {
+            return None;
+        }
+        let lowest = wrong.trailing_zeros() as usize;
+        // a wire carrying the wrong signal spoils whatever depends on it, so the other half of
+        // the swap feeds a wrong bit too
+        let suspects = (lowest..self.z.len())
+            .filter(|&i| wrong >> i & 1 == 1)
+            .flat_map(|i| self.cone(self.z[i]))
+            .collect::<BTreeSet<_>>();
+        let candidates = self
+            .cone(self.z[lowest])
+            .into_iter()
+            .cartesian_product(&suspects)
+            .filter(|&(a, &b)| a != b && self.unswapped(a) && self.unswapped(b))
+            .map(|(a, &b)| (a.min(b), a.max(b)))
+            .unique()
+            .collect_vec();
+        // try every swap at once, but keep the first that works for a stable answer
+        let fixed = parallel::map(&candidates, |&(a, b)| {
+            let mut simulator = self.clone();
+            simulator.swap(a, b);
+            (simulator.wrong_bits(batches, lowest + 1, true) == 0).then_some(simulator)
+        });
+        fixed
+            .into_iter()
+            .zip(candidates)
+            .find_map(|(simulator, swap)| {
+                let mut repaired = simulator?.local_repair(batches, swaps - 1)?;
+                repaired.push(swap);
+                Some(repaired)
+            })
+    }
+}
+
+impl Solution for Circuit {
+    type Part1 = u128;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let inputs: BTreeMap<String, bool> = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let (wire, value) = line
+                    .split_once(": ")
+                    .ok_or_else(|| ParseError::expected("an input", line))?;
+                let bit = wire
+                    .strip_prefix(['x', 'y'])
+                    .and_then(|bit| bit.parse::<u32>().ok());
+                if bit.is_none_or(|bit| bit >= 64) {
+                    return Err(ParseError::expected(
+                        "an input named x or y followed by a bit below 64",
+                        wire,
+                    ));
+                }
+                let value = match value {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(ParseError::expected("a bit", value)),
+                };
+                Ok((wire.to_owned(), value))
+            })
+            .collect::<Result<_, _>>()?;
+        static REGEX: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"^(\w+) (AND|OR|XOR) (\w+) -> (\w+)$").unwrap());
+        let lines = lines
+            .map(|line| {
+                let (_, [in1, op, in2, out]) = REGEX
+                    .captures(line)
+                    .ok_or_else(|| ParseError::expected("a gate", line))?
+                    .extract();
+                Ok((in1, op.parse::<Op>()?, in2, out))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let mut gates = BTreeMap::new();
+        for &(in1, op, in2, out) in &lines {
+            if inputs.contains_key(out) || gates.contains_key(out) {
+                return Err(ParseError::expected("a wire that nothing else drives", out));
+            }
+            gates.insert(out.to_owned(), (in1.to_owned(), op, in2.to_owned()));
+        }
+        for &(in1, _, in2, _) in &lines {
+            if let Some(wire) = [in1, in2]
+                .into_iter()
+                .find(|&wire| !inputs.contains_key(wire) && !gates.contains_key(wire))
+            {
+                return Err(ParseError::expected(
+                    "a wire driven by an input or a gate",
+                    wire,
+                ));
+            }
+        }
+        // outputs are numbered from z00 without gaps, so their names sort in bit order
+        let outputs = gates.keys().filter(|wire| wire.starts_with('z'));
+        for (i, wire) in outputs.enumerate() {
+            if i >= 100 || wire[1..].parse() != Ok(i) {
+                return Err(ParseError::new(format!(
+                    "expected outputs from z00 up to at most z99 with no gaps, found {wire}"
+                )));
+            }
+        }
+        Ok(Self {
+            inputs,
+            gates,
+            target: Target::default(),
+        })
+    }
+
+    /// the outputs as a number, which a `u128` holds since there are at most 100 of them
+    fn part1(&self) -> u128 {
+        let number = |bus: char| {
+            self.inputs
+                .iter()
+                .filter(|&(wire, &value)| wire.starts_with(bus) && value)
+                .fold(0, |number, (wire, _)| {
+                    number | 1 << wire[1..].parse::<u32>().unwrap()
+                })
+        };
+        let outputs = self
+            .simulator()
+            .run(&[(number('x'), number('y'))])
+            .expect("the outputs depend on a loop");
+        outputs[0]
+    }
+
+    /// the puzzle swaps four pairs of outputs, each within one bit of the adder, which the bit by
+    /// bit search finds without trying every set of four. circuits it can't repair go to the
+    /// exact search instead. with Z3, the swaps are proven right for every input, rather than
+    /// for the tested ones
+    fn part2(&self) -> String {
+        #[cfg(feature = "z3")]
+        let swaps = self.proven_repair(PUZZLE_SWAPS);
+        #[cfg(not(feature = "z3"))]
+        let swaps = self.find_repair(PUZZLE_SWAPS);
+        swaps
+            .expect("no swaps repair the circuit")
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .sorted()
+            .join(",")
+    }
+}
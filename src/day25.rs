@@ -0,0 +1,83 @@
+use itertools::Itertools;
+
+use crate::{ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Door {
+    keys: Vec<[u8; 5]>,
+    locks: Vec<[u8; 5]>,
+}
+
+const EMPTY: [u8; 5] = [0, 0, 0, 0, 0];
+const HEIGHT: u8 = 6;
+
+impl Door {
+    fn solve(&self) -> usize {
+        self.keys
+            .iter()
+            .cartesian_product(&self.locks)
+            .filter(|([k1, k2, k3, k4, k5], [l1, l2, l3, l4, l5])| {
+                k1 + l1 < HEIGHT
+                    && k2 + l2 < HEIGHT
+                    && k3 + l3 < HEIGHT
+                    && k4 + l4 < HEIGHT
+                    && k5 + l5 < HEIGHT
+            })
+            .count()
+    }
+}
+
+impl Solution for Door {
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut keys = vec![];
+        let mut locks = vec![];
+        let mut lines = input.lines();
+        while let Some(first) = lines.next() {
+            let is_key = match first {
+                "....." => true,
+                "#####" => false,
+                _ => return Err(ParseError::expected("a key or lock", first)),
+            };
+            let mut current = EMPTY;
+            for _ in 1..HEIGHT {
+                let line = match lines.next() {
+                    Some(line) if !line.is_empty() => line,
+                    _ => return Err(ParseError::expected("seven rows", first)),
+                };
+                if line.len() != 5 || !line.chars().all(|c| c == '#' || c == '.') {
+                    return Err(ParseError::expected("a row of five '#' or '.'", line));
+                }
+                for (i, c) in line.char_indices() {
+                    if c == '#' {
+                        current[i] += 1;
+                    }
+                }
+            }
+            match (is_key, lines.next()) {
+                (true, Some("#####")) => keys.push(current),
+                (false, Some(".....")) => locks.push(current),
+                (_, Some(line)) => {
+                    return Err(ParseError::expected("the last row of a key or lock", line))
+                }
+                (_, None) => return Err(ParseError::expected("seven rows", first)),
+            }
+            match lines.next() {
+                None | Some("") => {}
+                Some(line) => return Err(ParseError::expected("a blank line", line)),
+            }
+        }
+        Ok(Self { keys, locks })
+    }
+
+    fn part1(&self) -> usize {
+        self.solve()
+    }
+
+    /// there is no puzzle on the last day
+    fn part2(&self) -> &'static str {
+        "Merry Christmas!"
+    }
+}
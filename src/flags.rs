@@ -0,0 +1,19 @@
+use std::str::FromStr;
+
+/// the value following `flag` on the command line
+pub fn number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: {value}"))
+}
+
+/// a size such as `3x2` following `flag`, or `3` for something one tile high
+pub fn size(flag: &str, value: Option<String>) -> Result<(usize, usize), String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    let (width, height) = value.split_once('x').unwrap_or((&value, "1"));
+    match (width.parse(), height.parse()) {
+        (Ok(width @ 1..), Ok(height @ 1..)) => Ok((width, height)),
+        _ => Err(format!("invalid value for {flag}: {value}")),
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::BTreeMap,
+    ops::{self, IndexMut},
+};
+
+use crate::{geometry, Location, ParseError};
+
+/// a cell of a grid as `(row, col)`
+pub type Index = (usize, usize);
+
+/// a rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// the positions of marker characters found while parsing a grid, such as `S` and `E`
+#[derive(Debug, Default)]
+pub struct Markers(BTreeMap<char, Vec<Index>>);
+
+impl Markers {
+    /// every position of `marker`, in reading order
+    pub fn all(&self, marker: char) -> &[Index] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// the position of `marker`, which must appear exactly once, pointing at the second one if
+    /// there are more
+    pub fn unique(&self, marker: char) -> Result<Index, ParseError> {
+        match self.all(marker) {
+            &[point] => Ok(point),
+            [] => Err(ParseError::new(format!(
+                "expected exactly one {marker:?}, found none"
+            ))),
+            &[_, (row, col), ..] => Err(ParseError::expected(
+                format!("exactly one {marker:?}"),
+                &marker.to_string(),
+            )
+            .at(Location {
+                line: row + 1,
+                column: col + 1,
+            })),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// parses one cell per character, failing on characters `cell` does not recognize
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_with_markers(input, &[], cell).map(|(grid, _)| grid)
+    }
+
+    /// like `parse`, but also records where each of the `markers` characters is
+    pub fn parse_with_markers(
+        input: &str,
+        markers: &[char],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, Markers), ParseError> {
+        let mut cells = vec![];
+        let mut found = Markers::default();
+        let mut width = None;
+        let mut height = 0;
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (col, (i, c)) in line.char_indices().enumerate() {
+                if markers.contains(&c) {
+                    found.0.entry(c).or_default().push((row, col));
+                }
+                cells.push(cell(c).ok_or_else(|| {
+                    ParseError::expected("a known tile", &line[i..i + c.len_utf8()])
+                })?);
+            }
+            let len = cells.len() - before;
+            if *width.get_or_insert(len) != len {
+                return Err(ParseError::expected(
+                    format!("a row of {} tiles", width.unwrap()),
+                    line,
+                ));
+            }
+            height += 1;
+        }
+        let grid = Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        };
+        Ok((grid, found))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Index) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, point: Index) -> Option<&T> {
+        self.contains(point).then(|| &self[point])
+    }
+
+    pub fn get_mut(&mut self, point: Index) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self[point])
+        } else {
+            None
+        }
+    }
+
+    /// the cell at a signed `point`, if it lies inside the grid
+    pub fn at(&self, point: geometry::Point) -> Option<&T> {
+        self.get(point.index()?)
+    }
+
+    pub fn at_mut(&mut self, point: geometry::Point) -> Option<&mut T> {
+        self.get_mut(point.index()?)
+    }
+
+    /// every position in reading order
+    pub fn points(&self) -> impl Iterator<Item = Index> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// every position and its cell in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Index, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// the positions of the cells satisfying `predicate`, in reading order
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Index> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| (0..self.height).map(move |row| &self[(row, col)]))
+    }
+
+    /// the diagonals running from the top left to the bottom right, like `\`
+    pub fn backward_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (1..self.width + self.height).map(move |offset| {
+            // the diagonal through (row, col) where col + height = row + offset
+            (0..self.height).filter_map(move |row| {
+                let col = (row + offset).checked_sub(self.height)?;
+                self.get((row, col))
+            })
+        })
+    }
+
+    /// the diagonals running from the bottom left to the top right, like `/`
+    pub fn forward_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..(self.width + self.height).saturating_sub(1)).map(move |offset| {
+            (0..self.height).filter_map(move |row| self.get((row, offset.checked_sub(row)?)))
+        })
+    }
+
+    /// the positions up, right, down and left of `point` that are inside the grid
+    pub fn neighbours4(&self, (row, col): Index) -> impl Iterator<Item = Index> + '_ {
+        [
+            (row.wrapping_sub(1), col),
+            (row, col + 1),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+        ]
+        .into_iter()
+        .filter(|&point| self.contains(point))
+    }
+
+    /// the positions around `point` that are inside the grid, clockwise from up
+    pub fn neighbours8(&self, (row, col): Index) -> impl Iterator<Item = Index> + '_ {
+        let (up, left) = (row.wrapping_sub(1), col.wrapping_sub(1));
+        [
+            (up, col),
+            (up, col + 1),
+            (row, col + 1),
+            (row + 1, col + 1),
+            (row + 1, col),
+            (row + 1, left),
+            (row, left),
+            (up, left),
+        ]
+        .into_iter()
+        .filter(|&point| self.contains(point))
+    }
+}
+
+impl<T> ops::Index<Index> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Index) -> &T {
+        assert!(
+            self.contains((row, col)),
+            "({row}, {col}) is outside the grid"
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Index> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Index) -> &mut T {
+        assert!(
+            self.contains((row, col)),
+            "({row}, {col}) is outside the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> ops::Index<geometry::Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: geometry::Point) -> &T {
+        self.at(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<geometry::Point> for Grid<T> {
+    fn index_mut(&mut self, point: geometry::Point) -> &mut T {
+        self.at_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const YEAR: u16 = 2024;
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+/// how puzzle inputs are downloaded
+pub trait Transport {
+    /// fetches the body at `url`, authenticating with the session token
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+/// downloads over HTTP(S)
+pub struct Http;
+
+impl Transport for Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        ureq::get(url)
+            .set("Cookie", &format!("session={session}"))
+            .set(
+                "User-Agent",
+                concat!(env!("CARGO_PKG_NAME"), " (input cache)"),
+            )
+            .call()
+            .map_err(|err| err.to_string())?
+            .into_string()
+            .map_err(|err| err.to_string())
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// the input is not cached and there is no session token to download it with
+    Missing(PathBuf),
+    Fetch(String),
+    Io(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(path) => write!(
+                f,
+                "{} does not exist and no session token is configured",
+                path.display()
+            ),
+            Self::Fetch(err) => write!(f, "could not download input: {err}"),
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// puzzle inputs cached as `<dir>/<year>/<day>.txt`, downloaded once when missing
+pub struct Inputs<T = Http> {
+    dir: PathBuf,
+    session: Option<String>,
+    base_url: String,
+    transport: T,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            session: None,
+            base_url: BASE_URL.to_owned(),
+            transport: Http,
+        }
+    }
+}
+
+impl<T: Transport> Inputs<T> {
+    pub fn with_session(self, session: impl Into<String>) -> Self {
+        Self {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn with_transport<U: Transport>(self, transport: U) -> Inputs<U> {
+        Inputs {
+            dir: self.dir,
+            session: self.session,
+            base_url: self.base_url,
+            transport,
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(YEAR.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// reads the cached input, downloading it first if it is not cached yet
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+        if path.exists() {
+            return Ok(fs::read_to_string(path)?);
+        }
+        let Some(session) = &self.session else {
+            return Err(InputError::Missing(path));
+        };
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let input = self
+            .transport
+            .get(&url, session)
+            .map_err(InputError::Fetch)?;
+        store(&path, &input)?;
+        Ok(input)
+    }
+}
+
+/// writes to a temporary file first so an interrupted download never looks cached
+fn store(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents)?;
+    fs::rename(partial, path)
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod flags;
+pub mod geometry;
+pub mod grid;
+pub mod inputs;
+pub mod parallel;
+mod parse;
+pub mod render;
+pub mod search;
+pub mod vm;
+
+pub use parse::{Location, ParseError};
+
+/// a day's puzzle, parsed from its input and solvable in two parts
+pub trait Solution: Sized {
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+    fn part1(&self) -> Self::Part1;
+    fn part2(&self) -> Self::Part2;
+}
+
+/// parses the input, locating any error within it
+pub fn parse<S: Solution>(input: &str) -> Result<S, ParseError> {
+    S::parse(input).map_err(|err| err.locate(input))
+}
+
+/// parses the input and solves part 1
+pub fn part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    Ok(parse::<S>(input)?.part1().to_string())
+}
+
+/// parses the input and solves part 2
+pub fn part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    Ok(parse::<S>(input)?.part2().to_string())
+}
+
+/// solves one part of one day's puzzle given the puzzle input
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+/// every solved puzzle as `(day, part, solver)`, in order
+pub const SOLVERS: &[(u8, u8, Solver)] = &[
+    (1, 1, part1::<day01::Lists>),
+    (1, 2, part2::<day01::Lists>),
+    (2, 1, part1::<day02::Reports>),
+    (2, 2, part2::<day02::Reports>),
+    (3, 1, part1::<day03::Memory>),
+    (3, 2, part2::<day03::Memory>),
+    (4, 1, part1::<day04::Input>),
+    (4, 2, part2::<day04::Input>),
+    (5, 1, part1::<day05::Input>),
+    (5, 2, part2::<day05::Input>),
+    (6, 1, part1::<day06::Input>),
+    (6, 2, part2::<day06::Input>),
+    (7, 1, part1::<day07::Equations>),
+    (7, 2, part2::<day07::Equations>),
+    (8, 1, part1::<day08::Input>),
+    (8, 2, part2::<day08::Input>),
+    (9, 1, part1::<day09::DiskMap>),
+    (9, 2, part2::<day09::DiskMap>),
+    (10, 1, part1::<day10::Input>),
+    (10, 2, part2::<day10::Input>),
+    (11, 1, part1::<day11::Arrangement>),
+    (11, 2, part2::<day11::Arrangement>),
+    (12, 1, part1::<day12::Input>),
+    (12, 2, part2::<day12::Input>),
+    (13, 1, part1::<day13::Arcade>),
+    (13, 2, part2::<day13::Arcade>),
+    (14, 1, part1::<day14::Robots>),
+    (14, 2, part2::<day14::Robots>),
+    (15, 1, part1::<day15::Input>),
+    (15, 2, part2::<day15::Input>),
+    (16, 1, part1::<day16::Input>),
+    (16, 2, part2::<day16::Input>),
+    (17, 1, part1::<day17::Program<u64, ()>>),
+    (17, 2, part2::<day17::Program<u64, ()>>),
+    (18, 1, part1::<day18::Bytes>),
+    (18, 2, part2::<day18::Bytes>),
+    (19, 1, part1::<day19::Towels>),
+    (19, 2, part2::<day19::Towels>),
+    (20, 1, part1::<day20::Racetrack>),
+    (20, 2, part2::<day20::Racetrack>),
+    (21, 1, part1::<day21::Codes>),
+    (21, 2, part2::<day21::Codes>),
+    (22, 1, part1::<day22::Buyers>),
+    (22, 2, part2::<day22::Buyers>),
+    (23, 1, part1::<day23::Graph>),
+    (23, 2, part2::<day23::Graph>),
+    (24, 1, part1::<day24::Circuit>),
+    (24, 2, part2::<day24::Circuit>),
+    (25, 1, part1::<day25::Door>),
+    (25, 2, part2::<day25::Door>),
+];
+
+pub fn solver(day: u8, part: u8) -> Option<Solver> {
+    SOLVERS
+        .iter()
+        .find(|&&(d, p, _)| d == day && p == part)
+        .map(|&(_, _, solver)| solver)
+}
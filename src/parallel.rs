@@ -0,0 +1,91 @@
+use std::{
+    panic::resume_unwind,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// how many threads `map` may use, or 0 for one per CPU
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// how many threads every `map` running right now has spawned, which together with the thread
+/// that started the outermost one stays within `threads()`
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+/// limits `map` to `threads` threads, where 0 means one per CPU and 1 runs everything in order on
+/// the calling thread
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// how many threads `map` may use
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => num_cpus::get(),
+        threads => threads,
+    }
+}
+
+/// takes up to `wanted` threads from those no `map` is using, returning how many it got
+fn reserve(wanted: usize) -> usize {
+    let mut reserved = 0;
+    let _ = SPAWNED.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |spawned| {
+        reserved = wanted.min((threads() - 1).saturating_sub(spawned));
+        Some(spawned + reserved)
+    });
+    reserved
+}
+
+/// hands a reserved thread back once its worker is done, even if it panicked
+struct Reserved;
+
+impl Drop for Reserved {
+    fn drop(&mut self) {
+        SPAWNED.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// applies `f` to every item on the calling thread and up to `threads() - 1` others, returning
+/// the results in order
+///
+/// every `map` shares the same threads, so one called from within another's `f` gets whichever
+/// the outer one isn't using, and nesting never uses more than `threads()` threads
+pub fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync) -> Vec<U> {
+    let spawned = reserve(items.len().saturating_sub(1));
+    if spawned == 0 {
+        return items.iter().map(f).collect();
+    }
+    let reserved = (0..spawned).map(|_| Reserved).collect::<Vec<_>>();
+    // each thread takes the next unclaimed item, so slow items don't hold up a whole chunk
+    let next = AtomicUsize::new(0);
+    let work = || {
+        let mut done = vec![];
+        loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(i) else {
+                break done;
+            };
+            done.push((i, f(item)));
+        }
+    };
+    let done = thread::scope(|scope| {
+        let workers = reserved
+            .into_iter()
+            .map(|reserved| {
+                scope.spawn(move || {
+                    let _reserved = reserved;
+                    work()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut done = work();
+        for worker in workers {
+            done.extend(worker.join().unwrap_or_else(|panic| resume_unwind(panic)));
+        }
+        done
+    });
+    let mut results = (0..items.len()).map(|_| None).collect::<Vec<_>>();
+    for (i, result) in done {
+        results[i] = Some(result);
+    }
+    results.into_iter().map(Option::unwrap).collect()
+}
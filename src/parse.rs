@@ -0,0 +1,121 @@
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+/// where in the input a parse error happened, counting lines and columns from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// what the parser expected, like "expected a number"
+    pub message: String,
+    /// the offending text, if the error points at any
+    pub found: Option<String>,
+    /// where `found` starts, once the error has been located within the input
+    pub location: Option<Location>,
+    /// the address of `found`, which can be located if it was borrowed from the input
+    address: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            found: None,
+            location: None,
+            address: None,
+        }
+    }
+
+    /// an error pointing at `found`, which should be borrowed from the input so it can be located
+    pub fn expected(expectation: impl Display, found: &str) -> Self {
+        Self {
+            found: Some(found.to_owned()),
+            address: Some(found.as_ptr() as usize),
+            ..Self::new(format!("expected {expectation}"))
+        }
+    }
+
+    /// an error at a known `location`, for text that wasn't borrowed from the input
+    pub fn at(self, location: Location) -> Self {
+        Self {
+            location: Some(location),
+            ..self
+        }
+    }
+
+    /// works out the line and column of the offending text if it was borrowed from `input`
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = match self.address {
+            Some(address) if self.location.is_none() && address >= start => address - start,
+            _ => return self,
+        };
+        if let Some(before) = input.get(..offset) {
+            let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+            self.location = Some(Location {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            });
+        }
+        self
+    }
+
+    /// the offending line of `input` with the offending text underlined, once located
+    pub fn snippet(&self, input: &str) -> Option<String> {
+        let Location { line, column } = self.location?;
+        let text = input.lines().nth(line - 1)?;
+        let remaining = text.chars().count().saturating_sub(column - 1);
+        let width = self
+            .found
+            .as_ref()
+            .map_or(1, |found| found.chars().count())
+            .clamp(1, remaining.max(1));
+        let gutter = " ".repeat(line.to_string().len());
+        Some(format!(
+            "{line} | {text}\n{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        ))
+    }
+
+    /// describes the error for a user, along with the offending line of `input` if it can be
+    /// located there
+    pub fn report(&self, file: impl Display, input: &str) -> String {
+        let err = self.clone().locate(input);
+        let mut report = format!("could not parse {file}: {err}");
+        if let Some(snippet) = err.snippet(input) {
+            report = report + "\n" + &snippet;
+        }
+        report
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(Location { line, column }) = self.location {
+            write!(f, "line {line}, column {column}: ")?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(found) = &self.found {
+            write!(f, ", found {found:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// parses a number borrowed from the input, pointing at it if it is malformed
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::expected("a number", text))
+}
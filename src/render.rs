@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use crate::grid::{Grid, Index};
+
+/// a colour as its red, green and blue components
+pub type Rgb = [u8; 3];
+
+/// a picture with a colour for each pixel, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            pixels: Grid::new(width, height, background),
+        }
+    }
+
+    /// a pixel for each cell of `grid`, in the colour `palette` gives it
+    pub fn from_grid<T>(grid: &Grid<T>, palette: impl FnMut(&T) -> Rgb) -> Self {
+        Self {
+            pixels: grid.map(palette),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn pixel(&self, point: Index) -> Rgb {
+        self.pixels[point]
+    }
+
+    pub fn set(&mut self, point: Index, colour: Rgb) {
+        self.pixels[point] = colour;
+    }
+
+    /// blows each pixel up into a `scale` by `scale` square, since a pixel per tile is too small
+    /// to make out
+    pub fn scaled(&self, scale: usize) -> Self {
+        let mut scaled = Self::new(self.width() * scale, self.height() * scale, [0; 3]);
+        for (row, col) in scaled.pixels.points() {
+            scaled.pixels[(row, col)] = self.pixels[(row / scale, col / scale)];
+        }
+        scaled
+    }
+
+    /// the image as a binary PPM, the simplest format most viewers open
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        ppm.extend(self.pixels.iter().flat_map(|(_, pixel)| *pixel));
+        ppm
+    }
+
+    /// the image as a PNG, with its pixels stored rather than compressed so that it needs no
+    /// deflate implementation
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, RGB, then the only compression, filter and interlace methods
+        header.extend([8, 2, 0, 0, 0]);
+        // each row starts with its filter, which is none
+        let scanlines = self
+            .pixels
+            .rows()
+            .flat_map(|row| [0].into_iter().chain(row.iter().flatten().copied()))
+            .collect::<Vec<_>>();
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        chunk(&mut png, b"IHDR", &header);
+        chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+/// appends a PNG chunk, which carries a checksum of its type and data
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb88320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+/// `data` as a zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = data.chunks(u16::MAX as usize).collect::<Vec<_>>();
+    // an empty stream still needs one final block
+    let blocks = if blocks.is_empty() {
+        vec![&[][..]]
+    } else {
+        blocks
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        zlib.push(u8::from(i + 1 == blocks.len()));
+        let len = block.len() as u16;
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    zlib.extend((b << 16 | a).to_be_bytes());
+    zlib
+}
+
+/// an animated GIF that shows each of `frames` for `delay` hundredths of a second and loops
+/// forever, or None if they use more than 256 colours between them or are too big for a GIF
+///
+/// panics if the frames differ in size
+pub fn gif(frames: &[Image], delay: u16) -> Option<Vec<u8>> {
+    let (width, height) = frames
+        .first()
+        .map_or((0, 0), |frame| (frame.width(), frame.height()));
+    assert!(
+        frames
+            .iter()
+            .all(|frame| (frame.width(), frame.height()) == (width, height)),
+        "every frame must be the same size"
+    );
+    let (width, height) = (u16::try_from(width).ok()?, u16::try_from(height).ok()?);
+    let mut palette = vec![];
+    let mut indices = HashMap::new();
+    for frame in frames {
+        for (_, &pixel) in frame.pixels.iter() {
+            indices.entry(pixel).or_insert_with(|| {
+                palette.push(pixel);
+                palette.len() - 1
+            });
+        }
+    }
+    if palette.len() > 256 {
+        return None;
+    }
+    // the colour table holds a power of two colours, at least 2 of them
+    let bits = (palette.len().max(2) - 1).ilog2() + 1;
+    palette.resize(1 << bits, [0; 3]);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    gif.extend([0xf0 | (bits - 1) as u8, 0, 0]);
+    gif.extend(palette.iter().flatten());
+    // the Netscape extension that makes the animation loop forever
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+    for frame in frames {
+        gif.extend([0x21, 0xf9, 4, 0]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0]);
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(width.to_le_bytes());
+        gif.extend(height.to_le_bytes());
+        gif.push(0);
+        // LZW needs at least 2 bits per pixel, even for 2 colours
+        let min_bits = bits.max(2);
+        gif.push(min_bits as u8);
+        let pixels = frame.pixels.iter().map(|(_, pixel)| indices[pixel] as u16);
+        let data = lzw(pixels, min_bits);
+        for block in data.chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3b);
+    Some(gif)
+}
+
+/// packs codes of varying widths into bytes, least significant bit first, as GIF's LZW does
+struct Bits {
+    bytes: Vec<u8>,
+    buffer: u32,
+    len: u32,
+}
+
+impl Bits {
+    fn push(&mut self, code: u16, width: u32) {
+        self.buffer |= u32::from(code) << self.len;
+        self.len += width;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// compresses colour indices with the variant of LZW that GIF uses, starting at codes one bit
+/// wider than `min_bits` and clearing the table once it holds 4096 codes
+fn lzw(mut pixels: impl Iterator<Item = u16>, min_bits: u32) -> Vec<u8> {
+    let clear = 1 << min_bits;
+    let end = clear + 1;
+    let mut bits = Bits {
+        bytes: vec![],
+        buffer: 0,
+        len: 0,
+    };
+    let mut width = min_bits + 1;
+    let mut next = end + 1;
+    let mut table = HashMap::new();
+    bits.push(clear, width);
+    let Some(mut prefix) = pixels.next() else {
+        bits.push(end, width);
+        return bits.finish();
+    };
+    for pixel in pixels {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+        bits.push(prefix, width);
+        if next < 4096 {
+            table.insert((prefix, pixel), next);
+            next += 1;
+            // the decoder adds each code a step later, so it widens one code after we would
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            bits.push(clear, width);
+            table.clear();
+            width = min_bits + 1;
+            next = end + 1;
+        }
+        prefix = pixel;
+    }
+    bits.push(prefix, width);
+    bits.push(end, width);
+    bits.finish()
+}
@@ -0,0 +1,328 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// what a search found: the cheapest cost of every state it settled, and the goals it stopped at
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Paths<S, C> {
+    /// the cost of the cheapest path to `state`, if the search reached it
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// every state the search reached along with the cost of its cheapest path
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    /// every goal state reached at the cheapest goal cost
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// the cost of the cheapest path to any goal
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goals.first()?)
+    }
+
+    /// the states preceding `state` on its cheapest paths: only the first one unless the search
+    /// recorded every predecessor, and none for the starts
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// a cheapest path from a start to `end`, both included
+    pub fn path(&self, end: &S) -> Option<Vec<S>> {
+        self.costs.get(end)?;
+        let mut path = vec![end.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// every state lying on any cheapest path from a start to one of `ends`
+    pub fn on_cheapest_paths<'a>(&self, ends: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut stack = ends
+            .into_iter()
+            .filter(|end| self.costs.contains_key(end))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut seen: HashSet<S> = stack.iter().cloned().collect();
+        while let Some(state) = stack.pop() {
+            for prev in self.predecessors(&state) {
+                if seen.insert(prev.clone()) {
+                    stack.push(prev.clone());
+                }
+            }
+        }
+        seen
+    }
+}
+
+/// options shared by every kind of search
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Search {
+    all_predecessors: bool,
+}
+
+impl Search {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// remembers every predecessor on a cheapest path instead of just the first one found
+    pub fn with_all_predecessors(self) -> Self {
+        Self {
+            all_predecessors: true,
+        }
+    }
+
+    /// explores states in order of the number of steps taken, stopping once every goal at the
+    /// lowest number of steps has been reached
+    pub fn bfs<S, I>(
+        self,
+        starts: impl IntoIterator<Item = S>,
+        mut successors: impl FnMut(&S) -> I,
+        mut goal: impl FnMut(&S) -> bool,
+    ) -> Paths<S, usize>
+    where
+        S: Clone + Eq + Hash,
+        I: IntoIterator<Item = S>,
+    {
+        let mut paths = Paths {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        };
+        let mut queue = VecDeque::new();
+        for start in starts {
+            if let Entry::Vacant(vacant) = paths.costs.entry(start.clone()) {
+                vacant.insert(0);
+                queue.push_back((start, 0));
+            }
+        }
+        while let Some((state, steps)) = queue.pop_front() {
+            if paths.goal_cost().is_some_and(|best| steps > best) {
+                break;
+            }
+            if goal(&state) {
+                paths.goals.push(state);
+                continue;
+            }
+            for next in successors(&state) {
+                match paths.costs.entry(next.clone()) {
+                    Entry::Vacant(vacant) => {
+                        vacant.insert(steps + 1);
+                        paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                        queue.push_back((next, steps + 1));
+                    }
+                    Entry::Occupied(occupied) => {
+                        if self.all_predecessors && *occupied.get() == steps + 1 {
+                            paths
+                                .predecessors
+                                .get_mut(&next)
+                                .unwrap()
+                                .push(state.clone());
+                        }
+                    }
+                }
+            }
+        }
+        paths
+    }
+
+    /// explores states in order of path cost, stopping once every goal at the lowest cost has
+    /// been reached
+    pub fn dijkstra<S, C, I>(
+        self,
+        starts: impl IntoIterator<Item = S>,
+        successors: impl FnMut(&S) -> I,
+        goal: impl FnMut(&S) -> bool,
+    ) -> Paths<S, C>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.astar(starts, successors, |_| C::default(), goal)
+    }
+
+    /// like `dijkstra`, but explores states in order of path cost plus `heuristic`, which must
+    /// never overestimate the remaining cost to a goal
+    ///
+    /// a heuristic that also never drops by more than the cost of a step settles each state
+    /// once. otherwise a state can be settled before its cheapest path is found, and it is
+    /// explored again from the cheaper cost
+    pub fn astar<S, C, I>(
+        self,
+        starts: impl IntoIterator<Item = S>,
+        mut successors: impl FnMut(&S) -> I,
+        mut heuristic: impl FnMut(&S) -> C,
+        mut goal: impl FnMut(&S) -> bool,
+    ) -> Paths<S, C>
+    where
+        S: Clone + Eq + Hash,
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        // the cheapest cost found so far, which is final once the search ends if the state is
+        // settled
+        let mut costs = HashMap::new();
+        let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+        let mut settled = HashSet::new();
+        let mut goals = vec![];
+        let mut goal_cost = None;
+        let mut queue = BinaryHeap::new();
+        for start in starts {
+            costs.insert(start.clone(), C::default());
+            queue.push(Queued {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+        while let Some(Queued {
+            estimate,
+            cost,
+            state,
+        }) = queue.pop()
+        {
+            if goal_cost.is_some_and(|best| estimate > best) {
+                break;
+            }
+            if costs[&state] < cost || !settled.insert(state.clone()) {
+                continue;
+            }
+            if goal(&state) {
+                goal_cost = Some(cost);
+                goals.push(state);
+                continue;
+            }
+            for (next, step) in successors(&state) {
+                let cost = cost + step;
+                let improved = match costs.entry(next.clone()) {
+                    Entry::Vacant(vacant) => {
+                        vacant.insert(cost);
+                        true
+                    }
+                    Entry::Occupied(mut occupied) => match cost.cmp(occupied.get()) {
+                        Ordering::Less => {
+                            occupied.insert(cost);
+                            settled.remove(&next);
+                            true
+                        }
+                        Ordering::Equal => {
+                            // a start has no predecessors, even if a free move leads back to it
+                            if let (true, Some(others)) =
+                                (self.all_predecessors, predecessors.get_mut(&next))
+                            {
+                                others.push(state.clone());
+                            }
+                            false
+                        }
+                        Ordering::Greater => false,
+                    },
+                };
+                if improved {
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued {
+                        estimate: cost + heuristic(&next),
+                        cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+        // costs of unsettled states may not be the cheapest
+        costs.retain(|state, _| settled.contains(state));
+        predecessors.retain(|state, _| settled.contains(state));
+        Paths {
+            costs,
+            predecessors,
+            goals,
+        }
+    }
+}
+
+/// a state waiting in a search's queue, ordered so that the lowest estimate is popped first
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Reverse(&self.estimate).cmp(&Reverse(&other.estimate))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+/// breadth-first search recording one predecessor per state
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    Search::new().bfs(starts, successors, goal)
+}
+
+/// Dijkstra's algorithm recording one predecessor per state
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    Search::new().dijkstra(starts, successors, goal)
+}
+
+/// A* search recording one predecessor per state
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    Search::new().astar(starts, successors, heuristic, goal)
+}
@@ -0,0 +1,493 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Write},
+};
+
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{parse::number, ParseError};
+
+/// the registers of the chronospatial computer from day 17
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "a={} b={} c={}", self.a, self.b, self.c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    /// every opcode, in order of its number
+    pub const ALL: [Self; 8] = [
+        Self::Adv,
+        Self::Bxl,
+        Self::Bst,
+        Self::Jnz,
+        Self::Bxc,
+        Self::Out,
+        Self::Bdv,
+        Self::Cdv,
+    ];
+
+    pub fn decode(opcode: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(opcode)).copied()
+    }
+
+    pub fn encode(self) -> u8 {
+        self as u8
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Adv => "adv",
+            Self::Bxl => "bxl",
+            Self::Bst => "bst",
+            Self::Jnz => "jnz",
+            Self::Bxc => "bxc",
+            Self::Out => "out",
+            Self::Bdv => "bdv",
+            Self::Cdv => "cdv",
+        }
+    }
+
+    /// whether the operand is a combo operand rather than a literal one
+    pub fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv
+        )
+    }
+}
+
+/// what an operand stands for once its opcode is known
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Literal(u8),
+    A,
+    B,
+    C,
+    /// combo operand 7, which never appears in valid programs
+    Reserved,
+    /// the operand of `bxc`, which is read but ignored
+    Ignored(u8),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::A => write!(f, "a"),
+            Self::B => write!(f, "b"),
+            Self::C => write!(f, "c"),
+            Self::Reserved => write!(f, "?"),
+            Self::Ignored(operand) => write!(f, "{operand}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    /// the raw 3-bit operand
+    pub operand: u8,
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Option<Self> {
+        (operand < 8).then_some(Self {
+            opcode: Opcode::decode(opcode)?,
+            operand,
+        })
+    }
+
+    pub fn operand(&self) -> Operand {
+        match (self.opcode, self.operand) {
+            (Opcode::Bxc, operand) => Operand::Ignored(operand),
+            (opcode, operand) if !opcode.takes_combo() => Operand::Literal(operand),
+            (_, 0..=3) => Operand::Literal(self.operand),
+            (_, 4) => Operand::A,
+            (_, 5) => Operand::B,
+            (_, 6) => Operand::C,
+            _ => Operand::Reserved,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.operand() {
+            // bxc is written without its operand unless it has an unusual one worth keeping
+            Operand::Ignored(0) => write!(f, "{}", self.opcode.mnemonic()),
+            operand => write!(f, "{} {operand}", self.opcode.mnemonic()),
+        }
+    }
+}
+
+/// the instructions of a program along with their addresses, assuming no jump lands between an
+/// opcode and its operand
+pub fn disassemble(program: &[u8]) -> Vec<(usize, Instruction)> {
+    program
+        .chunks_exact(2)
+        .enumerate()
+        .filter_map(|(i, pair)| Some((2 * i, Instruction::decode(pair[0], pair[1])?)))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    /// the instruction at `ip` used combo operand 7
+    ReservedOperand { ip: usize },
+    /// the program was still running after the whole step budget
+    StepBudget { steps: usize },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReservedOperand { ip } => write!(f, "reserved combo operand 7 at {ip}"),
+            Self::StepBudget { steps } => write!(f, "still running after {steps} steps"),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+/// a chronospatial computer running a program
+#[derive(Debug, Clone)]
+pub struct Machine<'a> {
+    program: &'a [u8],
+    pub registers: Registers,
+    /// the instruction pointer
+    pub ip: usize,
+    pub output: Vec<u8>,
+    steps: usize,
+    budget: Option<usize>,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(program: &'a [u8], registers: Registers) -> Self {
+        Self {
+            program,
+            registers,
+            ip: 0,
+            output: vec![],
+            steps: 0,
+            budget: None,
+        }
+    }
+
+    /// gives up with `VmError::StepBudget` instead of running more than `budget` instructions
+    pub fn with_step_budget(self, budget: usize) -> Self {
+        Self {
+            budget: Some(budget),
+            ..self
+        }
+    }
+
+    /// how many instructions have run so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// the instruction at the instruction pointer, or None once the machine has halted
+    pub fn current(&self) -> Option<Instruction> {
+        match self.program.get(self.ip..self.ip + 2)? {
+            &[opcode, operand] => Instruction::decode(opcode, operand),
+            _ => None,
+        }
+    }
+
+    fn combo(&self, instruction: Instruction) -> Result<u64, VmError> {
+        match instruction.operand() {
+            Operand::Literal(literal) => Ok(literal.into()),
+            Operand::A => Ok(self.registers.a),
+            Operand::B => Ok(self.registers.b),
+            Operand::C => Ok(self.registers.c),
+            Operand::Reserved | Operand::Ignored(_) => {
+                Err(VmError::ReservedOperand { ip: self.ip })
+            }
+        }
+    }
+
+    /// runs one instruction and returns it, or None if the machine has halted
+    pub fn step(&mut self) -> Result<Option<Instruction>, VmError> {
+        let Some(instruction) = self.current() else {
+            return Ok(None);
+        };
+        if self.budget.is_some_and(|budget| self.steps >= budget) {
+            return Err(VmError::StepBudget { steps: self.steps });
+        }
+        let value = if instruction.opcode.takes_combo() {
+            self.combo(instruction)?
+        } else {
+            instruction.operand.into()
+        };
+        // shifting a u64 by 64 or more leaves nothing, rather than overflowing
+        let divide = |a: u64| {
+            a.checked_shr(value.try_into().unwrap_or(u32::MAX))
+                .unwrap_or(0)
+        };
+        let registers = &mut self.registers;
+        let mut next = self.ip + 2;
+        match instruction.opcode {
+            Opcode::Adv => registers.a = divide(registers.a),
+            Opcode::Bxl => registers.b ^= value,
+            Opcode::Bst => registers.b = value % 8,
+            Opcode::Jnz => {
+                if registers.a != 0 {
+                    next = instruction.operand.into();
+                }
+            }
+            Opcode::Bxc => registers.b ^= registers.c,
+            Opcode::Out => self.output.push((value % 8) as u8),
+            Opcode::Bdv => registers.b = divide(registers.a),
+            Opcode::Cdv => registers.c = divide(registers.a),
+        }
+        self.ip = next;
+        self.steps += 1;
+        Ok(Some(instruction))
+    }
+
+    /// runs until the machine halts, returning everything it output
+    pub fn run(&mut self) -> Result<&[u8], VmError> {
+        self.run_traced(|_, _| {})
+    }
+
+    /// like `run`, but calls `trace` with the machine and the instruction about to run
+    pub fn run_traced(
+        &mut self,
+        mut trace: impl FnMut(&Self, Instruction),
+    ) -> Result<&[u8], VmError> {
+        while let Some(instruction) = self.current() {
+            trace(self, instruction);
+            self.step()?;
+        }
+        Ok(&self.output)
+    }
+}
+
+/// parses the registers and program of a day 17 input
+pub fn parse(input: &str) -> Result<(Registers, Vec<u8>), ParseError> {
+    static REGISTERS: Lazy<[(char, Regex); 3]> = Lazy::new(|| {
+        ['A', 'B', 'C'].map(|name| {
+            let regex = Regex::new(&format!(r"^Register {name}: (\d+)$")).unwrap();
+            (name, regex)
+        })
+    });
+    let mut lines = input.lines();
+    let mut register = |(name, regex): &(char, Regex)| {
+        let line = lines.next().unwrap_or_default();
+        let captures = regex
+            .captures(line)
+            .ok_or_else(|| ParseError::expected(format_args!("register {name}"), line))?;
+        number(&captures[1])
+    };
+    let [a, b, c] = &*REGISTERS;
+    let registers = Registers {
+        a: register(a)?,
+        b: register(b)?,
+        c: register(c)?,
+    };
+    lines.next();
+    let program = parse_program(lines.next().unwrap_or_default())?;
+    Ok((registers, program))
+}
+
+/// parses a `Program: ...` line
+pub fn parse_program(line: &str) -> Result<Vec<u8>, ParseError> {
+    static REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Program: ((?:\d,)*\d)$").unwrap());
+    let values = REGEX
+        .captures(line)
+        .ok_or_else(|| ParseError::expected("a program", line))?
+        .get(1)
+        .unwrap()
+        .as_str()
+        .split(',')
+        .collect::<Vec<_>>();
+    if values.len() % 2 != 0 {
+        return Err(ParseError::expected(
+            "an operand after every opcode",
+            values[values.len() - 1],
+        ));
+    }
+    values
+        .into_iter()
+        .map(|n| {
+            number(n)
+                .ok()
+                .filter(|&n| n < 8)
+                .ok_or_else(|| ParseError::expected("a 3-bit number", n))
+        })
+        .collect()
+}
+
+/// the day 17 input that sets up `registers` and runs `program`, as read by `parse`
+pub fn format_input(registers: Registers, program: &[u8]) -> String {
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        registers.a,
+        registers.b,
+        registers.c,
+        program.iter().join(",")
+    )
+}
+
+fn label(address: usize) -> String {
+    format!("L{address}")
+}
+
+fn is_label(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// assembler source for a program, which `assemble` turns back into the same registers and
+/// program
+///
+/// jumps to the start of an instruction go to a label, and every instruction is commented with
+/// its address
+pub fn to_source(registers: Registers, program: &[u8]) -> String {
+    let instructions = disassemble(program);
+    let targets = instructions
+        .iter()
+        .filter(|(_, instruction)| instruction.opcode == Opcode::Jnz)
+        .map(|(_, instruction)| usize::from(instruction.operand))
+        .filter(|&target| target % 2 == 0 && target <= program.len())
+        .collect::<BTreeSet<_>>();
+    let mut source = format!(
+        ".a {}\n.b {}\n.c {}\n\n",
+        registers.a, registers.b, registers.c
+    );
+    for address in (0..=program.len()).step_by(2) {
+        if targets.contains(&address) {
+            writeln!(source, "{}:", label(address)).unwrap();
+        }
+        let Some(&(_, instruction)) = instructions.get(address / 2) else {
+            continue;
+        };
+        let text = match instruction.opcode {
+            Opcode::Jnz if targets.contains(&instruction.operand.into()) => {
+                format!("jnz {}", label(instruction.operand.into()))
+            }
+            _ => instruction.to_string(),
+        };
+        writeln!(source, "    {text:<10}; {address}").unwrap();
+    }
+    source
+}
+
+/// an assembled instruction whose operand may still refer to a label
+struct Pending<'a> {
+    opcode: Opcode,
+    mnemonic: &'a str,
+    operand: Option<&'a str>,
+}
+
+impl Pending<'_> {
+    fn operand(&self, labels: &HashMap<&str, usize>) -> Result<u8, ParseError> {
+        let Some(operand) = self.operand else {
+            return match self.opcode {
+                Opcode::Bxc => Ok(0),
+                _ => Err(ParseError::expected(
+                    format!("an operand for {}", self.mnemonic),
+                    self.mnemonic,
+                )),
+            };
+        };
+        if let Some(literal) = operand.parse().ok().filter(|&literal| literal < 8) {
+            return Ok(literal);
+        }
+        match (self.opcode, operand) {
+            (opcode, "a") if opcode.takes_combo() => Ok(4),
+            (opcode, "b") if opcode.takes_combo() => Ok(5),
+            (opcode, "c") if opcode.takes_combo() => Ok(6),
+            (opcode, "?") if opcode.takes_combo() => Ok(7),
+            (Opcode::Jnz, label) if labels.contains_key(label) => u8::try_from(labels[label])
+                .ok()
+                .filter(|&address| address < 8)
+                .ok_or_else(|| ParseError::expected("a label within the first 8 addresses", label)),
+            (Opcode::Jnz, _) => Err(ParseError::expected("a 3-bit address or a label", operand)),
+            (opcode, _) if opcode.takes_combo() => Err(ParseError::expected(
+                "a 3-bit number or a register",
+                operand,
+            )),
+            _ => Err(ParseError::expected("a 3-bit number", operand)),
+        }
+    }
+}
+
+/// assembles source with one instruction per line, like `adv 3` or `out a`, into registers and
+/// a program
+///
+/// `loop:` labels the next instruction so `jnz loop` can jump to it, `.a 729` sets a register,
+/// and `;` starts a comment
+pub fn assemble(source: &str) -> Result<(Registers, Vec<u8>), ParseError> {
+    let mut registers = Registers::default();
+    let mut labels = HashMap::new();
+    let mut pending = vec![];
+    for line in source.lines() {
+        let mut line = line.split(';').next().unwrap_or_default();
+        while let Some((label, rest)) = line.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(ParseError::expected("a label", label));
+            }
+            if labels.insert(label, 2 * pending.len()).is_some() {
+                return Err(ParseError::expected("a label not used before", label));
+            }
+            line = rest;
+        }
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [] => {}
+            [register, value] if register.starts_with('.') => {
+                let register = match register {
+                    ".a" => &mut registers.a,
+                    ".b" => &mut registers.b,
+                    ".c" => &mut registers.c,
+                    _ => return Err(ParseError::expected("a register", register)),
+                };
+                *register = number(value)?;
+            }
+            [mnemonic, ref operand @ ..] if operand.len() <= 1 => {
+                let opcode = Opcode::ALL
+                    .into_iter()
+                    .find(|opcode| opcode.mnemonic() == mnemonic)
+                    .ok_or_else(|| ParseError::expected("an instruction", mnemonic))?;
+                pending.push(Pending {
+                    opcode,
+                    mnemonic,
+                    operand: operand.first().copied(),
+                });
+            }
+            [.., extra] => return Err(ParseError::expected("the end of the line", extra)),
+        }
+    }
+    let program = pending
+        .iter()
+        .map(|instruction| Ok([instruction.opcode.encode(), instruction.operand(&labels)?]))
+        .collect::<Result<Vec<_>, ParseError>>()?
+        .concat();
+    Ok((registers, program))
+}
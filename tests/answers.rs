@@ -0,0 +1,68 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use advent_of_code_2024::{inputs::YEAR, SOLVERS};
+
+fn inputs() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join(YEAR.to_string())
+}
+
+/// checks every solution whose input and answer are both stored in `inputs`,
+/// e.g. `inputs/2024/01.txt` with `inputs/2024/01-1.answer` and `inputs/2024/01-2.answer`
+#[test]
+fn personal_answers() {
+    let inputs = inputs();
+    let mut wrong = vec![];
+    for &(day, part, solver) in SOLVERS {
+        let (Ok(input), Ok(expected)) = (
+            fs::read_to_string(inputs.join(format!("{day:02}.txt"))),
+            fs::read_to_string(inputs.join(format!("{day:02}-{part}.answer"))),
+        ) else {
+            continue;
+        };
+        let answer = solver(&input).unwrap();
+        if answer.trim_end() != expected.trim_end() {
+            wrong.push(format!(
+                "day {day:02} part {part}: expected {expected:?}, got {answer:?}"
+            ));
+        }
+    }
+    assert!(wrong.is_empty(), "{}", wrong.join("\n"));
+}
+
+/// the default day 17 search relies on how puzzle inputs loop, so check it against Z3
+#[cfg(feature = "z3")]
+#[test]
+fn day17_backends_agree() {
+    use advent_of_code_2024::{
+        day17::{Backend, Program},
+        Solution,
+    };
+
+    let Ok(input) = fs::read_to_string(inputs().join("17.txt")) else {
+        return;
+    };
+    let program = Program::<u64, ()>::parse(&input).unwrap();
+    assert_eq!(program.quine(Backend::Search), program.quine(Backend::Z3));
+}
+
+/// day 24 repairs are only tested on a few hundred inputs, so check Z3 proves them right
+#[cfg(feature = "z3")]
+#[test]
+#[ignore = "needs your day 24 input in inputs/2024/24.txt"]
+fn day24_repair_is_proven() {
+    use advent_of_code_2024::{day24::Circuit, Solution};
+
+    let path = inputs().join("24.txt");
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("could not read {}: {err}", path.display()));
+    let circuit = Circuit::parse(&input).unwrap();
+    let swaps = circuit
+        .proven_repair(4)
+        .expect("no swaps repair the circuit");
+    assert_eq!(circuit.with_swaps(&swaps).unwrap().prove(), Ok(()));
+}
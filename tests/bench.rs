@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use advent_of_code_2024::{
+    bench::{bench, regressions, sample, Report, Stage},
+    day01,
+};
+
+fn report(median: Duration) -> Report {
+    let input = include_str!("examples/01.txt");
+    let mut stats = bench(sample::<day01::Lists>, input, 3).unwrap();
+    for stats in stats.values_mut() {
+        stats.median = median;
+    }
+    Report::from([(1, stats)])
+}
+
+#[test]
+fn flags_slower_medians() {
+    let baseline = report(Duration::from_millis(10));
+    let within = report(Duration::from_millis(11));
+    assert!(regressions(&baseline, &within, 0.2).is_empty());
+    let slower = report(Duration::from_millis(13));
+    let flagged = regressions(&baseline, &slower, 0.2);
+    assert_eq!(
+        flagged
+            .iter()
+            .map(|regression| (regression.day, regression.stage))
+            .collect::<Vec<_>>(),
+        [(1, Stage::Parse), (1, Stage::Part1), (1, Stage::Part2)]
+    );
+}
+
+#[test]
+fn baseline_round_trips_through_json() {
+    let baseline = report(Duration::from_nanos(1234));
+    let json = serde_json::to_string(&baseline).unwrap();
+    assert!(json.contains(r#""median":1234"#));
+    let parsed: Report = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed[&1][&Stage::Part2].median, Duration::from_nanos(1234));
+    assert!(regressions(&baseline, &parsed, 0.0).is_empty());
+}
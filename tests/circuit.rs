@@ -0,0 +1,307 @@
+use advent_of_code_2024::{
+    day24::{BinaryOp, Circuit, Expr, Target},
+    parse, Solution,
+};
+
+/// a ripple-carry adder of `bits` bits with the outputs of each pair of gates swapped
+fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+    let mut input = String::new();
+    for wire in ["x", "y"] {
+        for i in 0..bits {
+            input += &format!("{wire}{i:02}: 0\n");
+        }
+    }
+    input += "\n";
+    let mut gates = vec![
+        ("x00 XOR y00".to_owned(), "z00".to_owned()),
+        ("x00 AND y00".to_owned(), "c00".to_owned()),
+    ];
+    for i in 1..bits {
+        let carry = if i + 1 == bits {
+            format!("z{bits:02}")
+        } else {
+            format!("c{i:02}")
+        };
+        gates.extend([
+            (format!("x{i:02} XOR y{i:02}"), format!("s{i:02}")),
+            (format!("x{i:02} AND y{i:02}"), format!("a{i:02}")),
+            (format!("s{i:02} XOR c{:02}", i - 1), format!("z{i:02}")),
+            (format!("s{i:02} AND c{:02}", i - 1), format!("t{i:02}")),
+            (format!("a{i:02} OR t{i:02}"), carry),
+        ]);
+    }
+    for (gate, output) in gates {
+        let output = swaps
+            .iter()
+            .fold(output, |output, &(a, b)| match output.as_str() {
+                wire if wire == a => b.to_owned(),
+                wire if wire == b => a.to_owned(),
+                _ => output,
+            });
+        input += &format!("{gate} -> {output}\n");
+    }
+    input
+}
+
+#[test]
+fn finds_the_fewest_swaps() {
+    let circuit: Circuit = parse(&adder(8, &[])).unwrap();
+    assert_eq!(circuit.repair(4), Some(vec![]));
+
+    let circuit: Circuit = parse(&adder(8, &[("s03", "a03"), ("z05", "c05")])).unwrap();
+    assert_eq!(circuit.part2(), "a03,c05,s03,z05");
+    assert_eq!(circuit.repair(4).map(|swaps| swaps.len()), Some(2));
+    assert_eq!(circuit.repair(1), None);
+    assert_eq!(circuit.local_repair(4), circuit.repair(4));
+}
+
+#[test]
+fn finds_repairs_the_local_search_misses() {
+    // z01 should be x01 ^ y01, which g0 computes without feeding any output
+    let input = "x00: 0\nx01: 0\ny00: 0\ny01: 0\n\n\
+                 y01 XOR x01 -> g0\nx00 XOR x00 -> g1\nx00 XOR g1 -> g2\n\
+                 y00 XOR g2 -> z00\ng2 OR y01 -> z01\n";
+    let circuit = parse::<Circuit>(input)
+        .unwrap()
+        .with_target("x ^ y".parse().unwrap());
+    let swap = ("g0".to_owned(), "z01".to_owned());
+    assert_eq!(circuit.repair(3), Some(vec![swap.clone()]));
+    assert_eq!(circuit.local_repair(3), None);
+    assert_eq!(circuit.find_repair(3), Some(vec![swap.clone()]));
+    assert_eq!(circuit.part2(), "g0,z01");
+    let repaired = circuit.with_swaps(&[swap]).unwrap();
+    assert_eq!(repaired.test(1000), Ok(()));
+}
+
+#[test]
+fn swaps_each_wire_at_most_once() {
+    // the outputs are rotated, which only two swaps sharing a wire would undo
+    let input = "x00: 0\nx01: 0\nx02: 0\ny00: 0\ny01: 0\ny02: 0\n\n\
+                 x00 AND y00 -> z02\nx01 AND y01 -> z00\nx02 AND y02 -> z01\n";
+    let circuit = parse::<Circuit>(input).unwrap().with_target(Target::And);
+    assert_eq!(circuit.repair(4), None);
+    assert_eq!(circuit.local_repair(4), None);
+}
+
+#[test]
+fn repairs_towards_any_target() {
+    let input = "x00: 0\nx01: 0\ny00: 0\ny01: 0\n\nx00 XOR y00 -> z01\nx01 XOR y01 -> z00\n";
+    let circuit: Circuit = parse(input).unwrap();
+    assert_eq!(circuit.clone().repair(4), None);
+    let circuit = circuit.with_target("x ^ y".parse().unwrap());
+    assert_eq!(circuit.part2(), "z00,z01");
+}
+
+#[test]
+fn parses_targets() {
+    assert_eq!("add".parse(), Ok(Target::Add));
+    assert_eq!(" and ".parse(), Ok(Target::And));
+    let expr: Expr = "x + y * 2 & 255".parse().unwrap();
+    assert_eq!(
+        expr,
+        Expr::Binary(
+            Box::new(Expr::Binary(
+                Box::new(Expr::X),
+                BinaryOp::Add,
+                Box::new(Expr::Binary(
+                    Box::new(Expr::Y),
+                    BinaryOp::Mul,
+                    Box::new(Expr::Number(2))
+                ))
+            )),
+            BinaryOp::And,
+            Box::new(Expr::Number(255))
+        )
+    );
+    let target: Target = "(x ^ ~y) << 1 >> 1".parse().unwrap();
+    assert_eq!(
+        target.eval(0b1010, 0b0110),
+        (0b1010 ^ !0b0110) & (u128::MAX >> 1)
+    );
+    assert_eq!("x - y".parse::<Target>().unwrap().eval(0, 1), u128::MAX);
+}
+
+#[test]
+fn rejects_malformed_targets() {
+    let err = "x + (y".parse::<Target>().unwrap_err();
+    assert_eq!(err.message, "expected a closing parenthesis");
+    let err = "x $ y".parse::<Target>().unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("$"));
+    let err = "2 * z".parse::<Target>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"expected x, y, a number or a parenthesis, found "z""#
+    );
+}
+
+#[test]
+fn rejects_dangling_wires() {
+    let err = parse::<Circuit>("x00: 1\ny00: 0\n\nx00 AND w00 -> z00\n")
+        .err()
+        .unwrap();
+    assert_eq!(err.found.as_deref(), Some("w00"));
+    let err = parse::<Circuit>("x00: 1\ny00: 0\n\nx00 AND y00 -> z01\n")
+        .err()
+        .unwrap();
+    assert!(err.message.contains("z00"), "{err}");
+}
+
+#[test]
+fn exports_verilog() {
+    let circuit: Circuit = parse(include_str!("examples/24-small.txt")).unwrap();
+    assert_eq!(
+        circuit.to_verilog("small"),
+        "module small (
+    input wire [2:0] x,
+    input wire [2:0] y,
+    output wire [2:0] z
+);
+    and g_z00 (z[0], x[0], y[0]);
+    xor g_z01 (z[1], x[1], y[1]);
+    or g_z02 (z[2], x[2], y[2]);
+endmodule
+"
+    );
+
+    let input = "x00: 0\ny00: 0\n\nx00 XOR y00 -> and\nand OR y00 -> z00\n";
+    let verilog = parse::<Circuit>(input).unwrap().to_verilog("circuit");
+    assert!(verilog.contains("    wire w_and;\n"), "{verilog}");
+    assert!(
+        verilog.contains("    xor g_and (w_and, x[0], y[0]);\n"),
+        "{verilog}"
+    );
+    assert!(
+        verilog.contains("    or g_z00 (z[0], w_and, y[0]);\n"),
+        "{verilog}"
+    );
+
+    let input = "x00: 0\ny00: 0\n\nx00 XOR y00 -> x70\nx70 AND y00 -> y05\ny05 OR y00 -> z00\n";
+    let verilog = parse::<Circuit>(input).unwrap().to_verilog("circuit");
+    assert!(
+        verilog.contains("    input wire [0:0] x,\n    input wire [0:0] y,\n"),
+        "{verilog}"
+    );
+    assert!(
+        verilog.contains("    wire w_x70;\n    wire w_y05;\n"),
+        "{verilog}"
+    );
+    assert!(
+        verilog.contains("    and g_y05 (w_y05, w_x70, y[0]);\n"),
+        "{verilog}"
+    );
+}
+
+#[test]
+fn exports_dot_with_suspects() {
+    let circuit: Circuit = parse(include_str!("examples/24-2.txt")).unwrap();
+    let circuit = circuit.with_target(Target::And);
+    let suspects = circuit.suspects();
+    assert_eq!(
+        suspects.iter().map(String::as_str).collect::<Vec<_>>(),
+        ["z00", "z01", "z02", "z05"]
+    );
+    let dot = circuit.to_dot(&suspects);
+    assert!(dot.starts_with("digraph circuit {\n"));
+    assert!(dot
+        .contains("    \"z03\" [label=\"AND\", shape=box, style=filled, fillcolor=lightblue];\n"));
+    assert!(dot.contains("    \"x05\" -> \"z00\" [label=\"x05\"];\n"));
+    assert!(dot.contains(
+        "    \"z05\" -> \"z05 out\" [label=\"z05\", color=red, fontcolor=red, penwidth=2];\n"
+    ));
+
+    // without a repair, the wrong outputs are the suspects
+    let input = "x00: 0\ny00: 0\n\nx00 OR y00 -> z00\n";
+    let circuit: Circuit = parse(input).unwrap();
+    let circuit = circuit.with_target(Target::And);
+    assert_eq!(circuit.suspects(), ["z00".to_owned()].into());
+}
+
+#[test]
+fn simulates_64_inputs_at_once() {
+    let circuit: Circuit = parse(&adder(40, &[])).unwrap();
+    let pairs = (0..100u64)
+        .map(|i| (i * 0x1234_5678 % (1 << 40), i * 0x9876_5432 % (1 << 40)))
+        .collect::<Vec<_>>();
+    let outputs = circuit.simulator().run(&pairs).unwrap();
+    let expected = pairs
+        .iter()
+        .map(|&(x, y)| u128::from(x + y))
+        .collect::<Vec<_>>();
+    assert_eq!(outputs, expected);
+
+    let input = "x00: 1\ny00: 1\n\nx00 AND z01 -> z00\nx00 AND z00 -> z01\n";
+    let circuit: Circuit = parse(input).unwrap();
+    assert_eq!(circuit.simulator().run(&[(1, 1)]), None);
+}
+
+#[test]
+fn reports_the_first_wrong_bit() {
+    let circuit: Circuit = parse(&adder(16, &[])).unwrap();
+    assert_eq!(circuit.test(1000), Ok(()));
+
+    let circuit: Circuit = parse(&adder(16, &[("z07", "t07"), ("z11", "c11")])).unwrap();
+    let mismatch = circuit.test(1000).unwrap_err();
+    assert_eq!(mismatch.bit, 7);
+    assert_eq!(mismatch.expected, u128::from(mismatch.x + mismatch.y));
+    let found = mismatch.found.unwrap();
+    assert_ne!(found >> 7 & 1, mismatch.expected >> 7 & 1);
+    assert_eq!(found & 0x7f, mismatch.expected & 0x7f);
+    assert!(mismatch.to_string().starts_with("z07 is wrong for x = "));
+
+    let input = "x00: 1\ny00: 1\n\nx00 AND z01 -> z00\nx00 AND z00 -> z01\n";
+    let circuit: Circuit = parse(input).unwrap();
+    let mismatch = circuit.test(0).unwrap_err();
+    assert_eq!((mismatch.bit, mismatch.found), (0, None));
+    assert_eq!(
+        mismatch.to_string(),
+        "z00 is wrong for x = 0, y = 0: it depends on a loop"
+    );
+}
+
+#[cfg(feature = "z3")]
+#[test]
+fn proves_circuits_right_or_wrong() {
+    let circuit: Circuit = parse(&adder(32, &[])).unwrap();
+    assert_eq!(circuit.prove(), Ok(()));
+
+    let circuit: Circuit = parse(&adder(32, &[("z09", "t09")])).unwrap();
+    let mismatch = circuit.prove().unwrap_err();
+    assert_eq!(mismatch.bit, 9);
+    assert_ne!(mismatch.found, Some(mismatch.expected));
+
+    let input = include_str!("examples/24-2.txt");
+    let circuit = parse::<Circuit>(input).unwrap().with_target(Target::And);
+    assert!(circuit.prove().is_err());
+    assert_eq!(circuit.proven_repair(2).map(|swaps| swaps.len()), Some(2));
+}
+
+#[cfg(feature = "z3")]
+#[test]
+fn proves_repairs() {
+    let circuit: Circuit = parse(&adder(24, &[("s03", "a03"), ("z15", "c15")])).unwrap();
+    let swaps = circuit.proven_repair(4).unwrap();
+    assert_eq!(Some(swaps.clone()), circuit.local_repair(4));
+    assert_eq!(circuit.proven_repair(1), None);
+
+    // the local search finds nothing here, so the exact one's answer is proven instead
+    let input = "x00: 0\nx01: 0\ny00: 0\ny01: 0\n\n\
+                 y01 XOR x01 -> g0\nx00 XOR x00 -> g1\nx00 XOR g1 -> g2\n\
+                 y00 XOR g2 -> z00\ng2 OR y01 -> z01\n";
+    let circuit = parse::<Circuit>(input)
+        .unwrap()
+        .with_target("x ^ y".parse().unwrap());
+    let swaps = circuit.proven_repair(3).unwrap();
+    assert_eq!(swaps, [("g0".to_owned(), "z01".to_owned())]);
+    assert_eq!(circuit.with_swaps(&swaps).unwrap().prove(), Ok(()));
+}
+
+#[test]
+fn reads_more_than_64_outputs() {
+    let mut input = "x00: 1\ny00: 1\n\n".to_owned();
+    for i in 0..80 {
+        input += &format!("x00 XOR y00 -> z{i:02}\n");
+    }
+    input += "x00 AND y00 -> z80\n";
+    let circuit: Circuit = parse(&input).unwrap();
+    assert_eq!(circuit.part1(), 1 << 80);
+}
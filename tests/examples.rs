@@ -0,0 +1,213 @@
+use advent_of_code_2024::*;
+
+fn parse<S: Solution>(input: &str) -> S {
+    S::parse(input).unwrap()
+}
+
+#[test]
+fn day01() {
+    let lists: day01::Lists = parse(include_str!("examples/01.txt"));
+    assert_eq!(lists.part1(), 11);
+    assert_eq!(lists.part2(), 31);
+}
+
+#[test]
+fn day02() {
+    let reports: day02::Reports = parse(include_str!("examples/02.txt"));
+    assert_eq!(reports.part1(), 2);
+    assert_eq!(reports.part2(), 4);
+}
+
+#[test]
+fn day03() {
+    let memory: day03::Memory = parse(include_str!("examples/03-1.txt"));
+    assert_eq!(memory.part1(), 161);
+    let memory: day03::Memory = parse(include_str!("examples/03-2.txt"));
+    assert_eq!(memory.part2(), 48);
+}
+
+#[test]
+fn day04() {
+    let input: day04::Input = parse(include_str!("examples/04.txt"));
+    assert_eq!(input.part1(), 18);
+    assert_eq!(input.part2(), 9);
+}
+
+#[test]
+fn day05() {
+    let input: day05::Input = parse(include_str!("examples/05.txt"));
+    assert_eq!(input.part1(), 143);
+    assert_eq!(input.part2(), 123);
+}
+
+#[test]
+fn day06() {
+    let input: day06::Input = parse(include_str!("examples/06.txt"));
+    assert_eq!(input.part1(), 41);
+    assert_eq!(input.part2(), 6);
+}
+
+#[test]
+fn day07() {
+    let equations: day07::Equations = parse(include_str!("examples/07.txt"));
+    assert_eq!(equations.part1(), 3749);
+    assert_eq!(equations.part2(), 11387);
+}
+
+#[test]
+fn day08() {
+    let input: day08::Input = parse(include_str!("examples/08.txt"));
+    assert_eq!(input.part1(), 14);
+    assert_eq!(input.part2(), 34);
+}
+
+#[test]
+fn day09() {
+    let disk_map: day09::DiskMap = parse(include_str!("examples/09.txt"));
+    assert_eq!(disk_map.part1(), 1928);
+    assert_eq!(disk_map.part2(), 2858);
+}
+
+#[test]
+fn day10() {
+    let input: day10::Input = parse(include_str!("examples/10.txt"));
+    assert_eq!(input.part1(), 36);
+    assert_eq!(input.part2(), 81);
+}
+
+#[test]
+fn day11() {
+    let arrangement: day11::Arrangement = parse(include_str!("examples/11.txt"));
+    assert_eq!(arrangement.part1(), 55312);
+    assert_eq!(arrangement.part2(), 65601038650482);
+}
+
+#[test]
+fn day12() {
+    let input: day12::Input = parse(include_str!("examples/12.txt"));
+    assert_eq!(input.part1(), 1930);
+    assert_eq!(input.part2(), 1206);
+}
+
+#[test]
+fn day13() {
+    let arcade: day13::Arcade = parse(include_str!("examples/13.txt"));
+    assert_eq!(arcade.part1(), 480);
+    assert_eq!(arcade.part2(), 875318608908);
+
+    // collinear buttons and a button that does not move along x leave nothing to divide by
+    let arcade: day13::Arcade = parse(
+        "Button A: X+1, Y+2\nButton B: X+2, Y+4\nPrize: X=3, Y=6\n\n\
+         Button A: X+0, Y+2\nButton B: X+3, Y+0\nPrize: X=6, Y=4\n",
+    );
+    assert_eq!(arcade.part1(), 0);
+    assert_eq!(arcade.part2(), 0);
+}
+
+/// part 2 has no example: the christmas tree only appears in real inputs
+#[test]
+fn day14() {
+    let robots = parse::<day14::Robots>(include_str!("examples/14.txt")).with_area(11, 7);
+    assert_eq!(robots.part1(), 12);
+}
+
+#[test]
+fn day15() {
+    let input: day15::Input = parse(include_str!("examples/15-small.txt"));
+    assert_eq!(input.part1(), 2028);
+    let input: day15::Input = parse(include_str!("examples/15-wide.txt"));
+    assert_eq!(input.part2(), 618);
+    let input: day15::Input = parse(include_str!("examples/15.txt"));
+    assert_eq!(input.part1(), 10092);
+    assert_eq!(input.part2(), 9021);
+}
+
+#[test]
+fn day16() {
+    let input: day16::Input = parse(include_str!("examples/16.txt"));
+    assert_eq!(input.part1(), 7036);
+    assert_eq!(input.part2(), 45);
+}
+
+#[test]
+fn day17() {
+    let program: day17::Program<u64, ()> = parse(include_str!("examples/17-1.txt"));
+    assert_eq!(program.part1(), "4,6,3,5,6,3,5,2,1,0");
+    let program: day17::Program<u64, ()> = parse(include_str!("examples/17-2.txt"));
+    assert_eq!(program.part2(), 117440);
+}
+
+#[test]
+fn day18() {
+    let bytes = parse::<day18::Bytes>(include_str!("examples/18.txt")).with_size(7, 12);
+    assert_eq!(bytes.part1(), 22);
+    assert_eq!(bytes.part2(), "6,1");
+
+    let bytes = parse::<day18::Bytes>(
+        "1,1
+",
+    )
+    .with_size(7, 1);
+    assert_eq!(bytes.part2(), "never blocked");
+}
+
+#[test]
+fn day19() {
+    let towels: day19::Towels = parse(include_str!("examples/19.txt"));
+    assert_eq!(towels.part1(), 6);
+    assert_eq!(towels.part2(), 16);
+}
+
+#[test]
+fn day20() {
+    let racetrack: day20::Racetrack = parse(include_str!("examples/20.txt"));
+    let racetrack = racetrack.with_minimum_improvement(20);
+    assert_eq!(racetrack.part1(), 5);
+    let racetrack = racetrack.with_minimum_improvement(64);
+    assert_eq!(racetrack.part1(), 1);
+    let racetrack = racetrack.with_minimum_improvement(50);
+    assert_eq!(racetrack.part2(), 285);
+    let racetrack = racetrack.with_minimum_improvement(76);
+    assert_eq!(racetrack.part2(), 3);
+}
+
+#[test]
+fn day21() {
+    let codes: day21::Codes = parse(include_str!("examples/21.txt"));
+    assert_eq!(codes.part1(), 126384);
+    assert_eq!(codes.part2(), 154115708116294);
+}
+
+#[test]
+fn day22() {
+    let buyers: day22::Buyers = parse(include_str!("examples/22-1.txt"));
+    assert_eq!(buyers.part1(), 37327623);
+    let buyers: day22::Buyers = parse(include_str!("examples/22-2.txt"));
+    assert_eq!(buyers.part2(), 23);
+}
+
+#[test]
+fn day23() {
+    let graph: day23::Graph = parse(include_str!("examples/23.txt"));
+    assert_eq!(graph.part1(), 7);
+    assert_eq!(graph.part2(), "co,de,ka,ta");
+}
+
+#[test]
+fn day24() {
+    let circuit: day24::Circuit = parse(include_str!("examples/24-small.txt"));
+    assert_eq!(circuit.part1(), 4);
+    let circuit: day24::Circuit = parse(include_str!("examples/24.txt"));
+    assert_eq!(circuit.part1(), 2024);
+    // the example in part 2 swaps wires of an AND circuit rather than an adder
+    let circuit: day24::Circuit = parse(include_str!("examples/24-2.txt"));
+    let circuit = circuit.with_target(day24::Target::And);
+    assert_eq!(circuit.part2(), "z00,z01,z02,z05");
+}
+
+#[test]
+fn day25() {
+    let door: day25::Door = parse(include_str!("examples/25.txt"));
+    assert_eq!(door.part1(), 3);
+    assert_eq!(door.part2(), "Merry Christmas!");
+}
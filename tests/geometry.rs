@@ -0,0 +1,51 @@
+use advent_of_code_2024::geometry::{Direction, Point, Vector};
+
+#[test]
+fn arithmetic() {
+    let a = Point::new(2, 3);
+    let b = Point::new(5, -1);
+    assert_eq!(b - a, Vector::new(3, -4));
+    assert_eq!(a + (b - a) * 2, Point::new(8, -5));
+    assert_eq!(b - (b - a), a);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a + Direction::Up, Point::new(1, 3));
+}
+
+#[test]
+fn turns() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        assert_eq!(
+            direction.vector() + direction.reverse().vector(),
+            Vector::default()
+        );
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+}
+
+#[test]
+fn parsing() {
+    let arrows = "^>v<".chars().map(Direction::from_arrow);
+    let letters = "URDL".chars().map(Direction::from_letter);
+    assert!(arrows.eq(Direction::ALL.map(Some)));
+    assert!(letters.eq(Direction::ALL.map(Some)));
+    assert_eq!(Direction::from_arrow('U'), None);
+    assert_eq!(Direction::from_letter('^'), None);
+    assert_eq!(
+        Direction::ALL
+            .map(Direction::arrow)
+            .iter()
+            .collect::<String>(),
+        "^>v<"
+    );
+}
+
+#[test]
+fn grid_indices() {
+    assert_eq!(Point::new(1, 2).index(), Some((1, 2)));
+    assert_eq!(Point::new(-1, 2).index(), None);
+    assert_eq!(Point::new(1, -2).index(), None);
+    assert_eq!(Point::from((4, 7)), Point::new(4, 7));
+}
@@ -0,0 +1,62 @@
+use advent_of_code_2024::grid::Grid;
+
+fn letters() -> Grid<char> {
+    Grid::parse("abc\ndef", Some).unwrap()
+}
+
+fn strings<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+    lines.map(|line| line.collect()).collect()
+}
+
+#[test]
+fn lines() {
+    let grid = letters();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(
+        grid.rows()
+            .map(|row| row.iter().collect())
+            .collect::<Vec<String>>(),
+        ["abc", "def"]
+    );
+    assert_eq!(strings(grid.columns()), ["ad", "be", "cf"]);
+    assert_eq!(strings(grid.backward_diagonals()), ["d", "ae", "bf", "c"]);
+    assert_eq!(strings(grid.forward_diagonals()), ["a", "bd", "ce", "f"]);
+}
+
+#[test]
+fn neighbours() {
+    let grid = letters();
+    assert_eq!(
+        grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(
+        grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+        [(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]
+    );
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid[(1, 2)], 'f');
+}
+
+#[test]
+fn markers() {
+    let (grid, markers) = Grid::parse_with_markers("S.#\n.#E", &['S', 'E'], |c| match c {
+        '#' => Some(false),
+        '.' | 'S' | 'E' => Some(true),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(markers.unique('S'), Ok((0, 0)));
+    assert_eq!(markers.unique('E'), Ok((1, 2)));
+    assert!(markers.unique('X').is_err());
+    assert_eq!(
+        grid.positions(|&open| !open).collect::<Vec<_>>(),
+        [(0, 2), (1, 1)]
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Grid::parse("ab\nc", Some).is_err());
+    assert!(Grid::parse("ab\ncd", |c| (c != 'd').then_some(c)).is_err());
+}
@@ -0,0 +1,103 @@
+use std::{
+    cell::RefCell,
+    env, fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    process, thread,
+};
+
+use advent_of_code_2024::inputs::{InputError, Inputs, Transport};
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-inputs-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[derive(Default)]
+struct Stub {
+    requests: RefCell<Vec<(String, String)>>,
+}
+
+impl Transport for &Stub {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        self.requests
+            .borrow_mut()
+            .push((url.to_owned(), session.to_owned()));
+        Ok("1 2\n".to_owned())
+    }
+}
+
+#[test]
+fn downloads_once() {
+    let dir = scratch("once");
+    let stub = Stub::default();
+    let inputs = Inputs::new(&dir)
+        .with_session("token")
+        .with_transport(&stub);
+    assert_eq!(inputs.get(16).unwrap(), "1 2\n");
+    assert_eq!(inputs.get(16).unwrap(), "1 2\n");
+    assert_eq!(
+        *stub.requests.borrow(),
+        [(
+            "https://adventofcode.com/2024/day/16/input".to_owned(),
+            "token".to_owned()
+        )]
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("2024").join("16.txt")).unwrap(),
+        "1 2\n"
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn never_downloads_cached() {
+    let dir = scratch("cached");
+    fs::create_dir_all(dir.join("2024")).unwrap();
+    fs::write(dir.join("2024").join("01.txt"), "cached\n").unwrap();
+    let stub = Stub::default();
+    let inputs = Inputs::new(&dir)
+        .with_session("token")
+        .with_transport(&stub);
+    assert_eq!(inputs.get(1).unwrap(), "cached\n");
+    assert!(stub.requests.borrow().is_empty());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn missing_without_session() {
+    let dir = scratch("missing");
+    let stub = Stub::default();
+    let inputs = Inputs::new(&dir).with_transport(&stub);
+    assert!(matches!(inputs.get(1), Err(InputError::Missing(_))));
+    assert!(stub.requests.borrow().is_empty());
+}
+
+#[test]
+fn downloads_over_http() {
+    let dir = scratch("http");
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request = BufReader::new(&stream)
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n3 4 5\n")
+            .unwrap();
+        request
+    });
+    let inputs = Inputs::new(&dir)
+        .with_session("token")
+        .with_base_url(format!("http://{address}"));
+    assert_eq!(inputs.get(7).unwrap(), "3 4 5\n");
+    let request = server.join().unwrap();
+    assert_eq!(request[0], "GET /2024/day/7/input HTTP/1.1");
+    assert!(request.contains(&"Cookie: session=token".to_owned()));
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,86 @@
+use advent_of_code_2024::{
+    day21::{Codes, Costs, Keypad, PressError},
+    parse, Location, Solution,
+};
+
+#[test]
+fn counts_presses_at_any_depth() {
+    let costs = |robots| Costs::new(&Keypad::numeric(), &Keypad::directional(), robots);
+    // the lengths of the sequences in the puzzle text for typing 029A
+    for (robots, presses) in [(0, 12), (1, 28), (2, 68)] {
+        let costs = costs(robots);
+        assert_eq!(costs.door(), robots + 1);
+        assert_eq!(costs.presses(costs.door(), "029A".chars()), Some(presses));
+    }
+    assert_eq!(costs(2).presses(0, "<A^A".chars()), Some(4));
+    assert_eq!(costs(2).presses(3, "0B".chars()), None);
+
+    let codes: Codes = parse(include_str!("examples/21.txt")).unwrap();
+    assert_eq!(codes.complexity(2), Some(codes.part1()));
+    assert_eq!(codes.complexity(25), Some(codes.part2()));
+    // each robot multiplies the presses by about two and a half, which overflows long before 100
+    assert_eq!(codes.complexity(100), None);
+}
+
+#[test]
+fn types_on_other_layouts() {
+    let numeric: Keypad = " 0A\n123\n456\n789".parse().unwrap();
+    let codes: Codes = parse("029A\n").unwrap();
+    let flipped = codes.with_keypads(numeric, Keypad::directional());
+    // flipping the keypad upside down turns ups into downs, which cost as much to press here
+    assert_eq!(flipped.complexity(0), Some(12 * 29));
+
+    // with no gap, the arm can take any shortest way
+    let directional: Keypad = "<^A\nv>#".parse().unwrap();
+    let codes = flipped.with_keypads(Keypad::numeric(), directional);
+    assert!(codes.complexity(2).is_some());
+
+    // a keypad missing an arrow can't move the arm that way
+    let directional: Keypad = " ^A\n v>".parse().unwrap();
+    let codes = codes.with_keypads(Keypad::numeric(), directional);
+    assert_eq!(codes.complexity(1), None);
+}
+
+#[test]
+fn rejects_malformed_layouts() {
+    let input = "12A\n3A4";
+    let err = input.parse::<Keypad>().unwrap_err().locate(input);
+    assert_eq!(err.message, "expected a key not already on the keypad");
+    assert_eq!(err.found.as_deref(), Some("A"));
+    assert_eq!(err.location, Some(Location { line: 2, column: 2 }));
+    let err = "123".parse::<Keypad>().unwrap_err();
+    assert_eq!(err.message, "expected a keypad with an A key");
+    let err = "12A\n3".parse::<Keypad>().unwrap_err();
+    assert_eq!(err.message, "expected a row of 3 tiles");
+}
+
+#[test]
+fn reconstructs_the_presses_on_every_layer() {
+    let codes: Codes = parse(include_str!("examples/21.txt")).unwrap();
+    let sequences = codes.sequences("029A", 2).unwrap();
+    assert_eq!(sequences.len(), 4);
+    assert_eq!(sequences[3], "029A");
+    assert_eq!(sequences[2].len(), "<A^A>^^AvvvA".len());
+    assert_eq!(sequences[1].len(), "v<<A>>^A<A>AvA<^AA>A<vAAA>^A".len());
+    assert_eq!(sequences[0].len(), 68);
+    assert_eq!(Keypad::numeric().drive(&sequences[2]).unwrap(), "029A");
+    for code in ["980A", "179A", "456A", "379A"] {
+        let sequences = codes.sequences(code, 3).unwrap();
+        assert_eq!(Some(sequences[0].len()), codes.presses(code, 3));
+    }
+}
+
+#[test]
+fn drives_arms_around_the_gap() {
+    let numeric = Keypad::numeric();
+    assert_eq!(numeric.drive("<A^A>^^AvvvA"), Ok("029A".to_owned()));
+    assert_eq!(numeric.drive("<<A"), Err(PressError::Gap { press: 1 }));
+    assert_eq!(
+        Keypad::directional().drive("vx"),
+        Err(PressError::Unknown { press: 1, key: 'x' })
+    );
+    assert_eq!(
+        PressError::Gap { press: 1 }.to_string(),
+        "press 1 points the arm at a gap"
+    );
+}
@@ -0,0 +1,87 @@
+use advent_of_code_2024::{
+    day16::{Input, Move},
+    geometry::{Direction, Point},
+    parse, Solution,
+};
+
+const SMALL: &str = "#####\n#E.S#\n#####\n";
+
+/// where following `route` through `maze` from the start ends up, and the score on the way, or
+/// None if it walks into a wall
+fn follow(maze: &str, route: &[Move], turn: usize, step: usize) -> Option<(char, usize)> {
+    let rows = maze.lines().map(|row| row.as_bytes()).collect::<Vec<_>>();
+    let at = |point: Point| rows[point.row as usize][point.col as usize] as char;
+    let row = rows.iter().position(|row| row.contains(&b'S')).unwrap();
+    let col = rows[row].iter().position(|&c| c == b'S').unwrap();
+    let (mut position, mut direction) = (Point::new(row as i64, col as i64), Direction::Right);
+    let mut score = 0;
+    for m in route {
+        match m {
+            Move::Forward => {
+                position = position + direction;
+                if at(position) == '#' {
+                    return None;
+                }
+                score += step;
+            }
+            Move::Left => direction = direction.turn_left(),
+            Move::Right => direction = direction.turn_right(),
+            Move::Around => direction = direction.reverse(),
+        }
+        if *m != Move::Forward {
+            score += turn;
+        }
+    }
+    Some((at(position), score))
+}
+
+#[test]
+fn follows_a_best_route() {
+    let example = include_str!("examples/16.txt");
+    let maze: Input = parse(example).unwrap();
+    let route = maze.solve().route().unwrap();
+    assert_eq!(follow(example, &route, 1000, 1), Some(('E', 7036)));
+
+    for (turn, step) in [(0, 1), (1, 1), (1, 1000), (500, 3)] {
+        let maze = parse::<Input>(example)
+            .unwrap()
+            .with_turn_cost(turn)
+            .with_step_cost(step);
+        let score = maze.solve().score().unwrap();
+        let route = maze.solve().route().unwrap();
+        assert_eq!(follow(example, &route, turn, step), Some(('E', score)));
+    }
+    // without turns to pay for, the best route is the shortest
+    let maze = maze.with_turn_cost(0).with_step_cost(1);
+    assert_eq!(maze.solve().score(), Some(28));
+}
+
+#[test]
+fn turns_around_if_allowed() {
+    let maze: Input = parse(SMALL).unwrap();
+    assert_eq!(maze.solve().score(), Some(2002));
+    let letters = |maze: &Input| {
+        let route = maze.solve().route().unwrap();
+        route.iter().map(|m| m.letter()).collect::<String>()
+    };
+    assert!(["LLFF", "RRFF"].contains(&letters(&maze).as_str()));
+    let maze = maze.with_u_turns(true);
+    assert_eq!(maze.solve().score(), Some(1002));
+    assert_eq!(letters(&maze), "UFF");
+    assert_eq!(maze.part2(), 3);
+}
+
+#[test]
+fn marks_the_best_seats() {
+    let maze: Input = parse(include_str!("examples/16.txt")).unwrap();
+    let picture = maze.solve().picture();
+    assert_eq!(picture.lines().nth(7), Some("#..OOOOOOOOO#O#"));
+    assert_eq!(picture.lines().nth(13), Some("#S..#.....#OOO#"));
+    assert_eq!(picture.matches('O').count() + 2, maze.part2());
+
+    let maze: Input = parse("#####\n#.#E#\n#S..#\n#####\n").unwrap();
+    assert_eq!(maze.solve().picture(), "#####\n#.#E#\n#SOO#\n#####\n");
+    let walled: Input = parse("#####\n#S#E#\n#####\n").unwrap();
+    assert_eq!(walled.solve().score(), None);
+    assert_eq!(walled.solve().route(), None);
+}
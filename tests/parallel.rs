@@ -0,0 +1,64 @@
+use std::{
+    collections::HashSet,
+    panic::catch_unwind,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::{self, ThreadId},
+    time::Duration,
+};
+
+use advent_of_code_2024::parallel;
+
+/// the thread count is global, so everything that changes it lives in one test
+#[test]
+fn maps_in_order() {
+    let items = (0..1000).collect::<Vec<u64>>();
+    let squares = items.iter().map(|x| x * x).collect::<Vec<_>>();
+    let main = thread::current().id();
+    let slowly = |_: &u64| {
+        thread::sleep(Duration::from_millis(1));
+        thread::current().id()
+    };
+
+    parallel::set_threads(4);
+    assert_eq!(parallel::threads(), 4);
+    assert_eq!(parallel::map(&items, |x| x * x), squares);
+    let threads = parallel::map(&items[..100], slowly)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert!((2..=4).contains(&threads.len()), "{threads:?}");
+
+    parallel::set_threads(1);
+    assert_eq!(parallel::map(&items, |x| x * x), squares);
+    let threads = parallel::map(&items, |_| thread::current().id());
+    assert!(threads.iter().all(|&id: &ThreadId| id == main));
+
+    // maps inside a map share its threads, using those it leaves idle but never more
+    parallel::set_threads(4);
+    let running = AtomicUsize::new(0);
+    let most_running = AtomicUsize::new(0);
+    let inner = parallel::map(&items[..2], |_| {
+        parallel::map(&items[..20], |item| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most_running.fetch_max(now, Ordering::SeqCst);
+            let id = slowly(item);
+            running.fetch_sub(1, Ordering::SeqCst);
+            id
+        })
+    });
+    let threads = inner.into_iter().flatten().collect::<HashSet<_>>();
+    assert!(threads.len() > 2, "{threads:?}");
+    assert!(most_running.load(Ordering::SeqCst) <= 4);
+
+    parallel::set_threads(3);
+    let panicked = catch_unwind(|| parallel::map(&items, |&x| assert!(x != 500)));
+    assert!(panicked.is_err());
+    // the threads of a map that panicked are free again
+    parallel::set_threads(2);
+    let threads = parallel::map(&items[..100], slowly)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(threads.len(), 2);
+
+    parallel::set_threads(0);
+    assert_eq!(parallel::threads(), num_cpus::get());
+}
@@ -0,0 +1,104 @@
+use advent_of_code_2024::{day01, day13, day14, day15, day24, day25, parse, Location, ParseError};
+
+fn error<S: advent_of_code_2024::Solution>(input: &str) -> ParseError {
+    parse::<S>(input).err().expect("expected a parse error")
+}
+
+#[test]
+fn locates_malformed_numbers() {
+    let input = "3   4\n4   x3\n";
+    let err = error::<day01::Lists>(input);
+    assert_eq!(err.location, Some(Location { line: 2, column: 5 }));
+    assert_eq!(err.found.as_deref(), Some("x3"));
+    assert_eq!(
+        err.to_string(),
+        r#"line 2, column 5: expected a number, found "x3""#
+    );
+    assert_eq!(err.snippet(input).unwrap(), "2 | 4   x3\n  |     ^^");
+    assert_eq!(
+        err.report("lists.txt", input),
+        "could not parse lists.txt: line 2, column 5: expected a number, found \"x3\"\n\
+         2 | 4   x3\n  |     ^^"
+    );
+}
+
+#[test]
+fn locates_errors_past_blank_lines() {
+    let input = include_str!("examples/13.txt").replacen("X+26", "X=26", 1);
+    let err = error::<day13::Arcade>(&input);
+    assert_eq!(err.location, Some(Location { line: 5, column: 1 }));
+
+    let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3garbage\n";
+    let err = error::<day14::Robots>(input);
+    assert_eq!(err.found.as_deref(), Some("p=6,3 v=-1,-3garbage"));
+    assert_eq!(err.location, Some(Location { line: 2, column: 1 }));
+
+    let input = include_str!("examples/15-small.txt").replacen("<^^>", "<^?>", 1);
+    let err = error::<day15::Input>(&input);
+    assert_eq!(err.found.as_deref(), Some("?"));
+    assert_eq!(
+        err.location,
+        Some(Location {
+            line: 10,
+            column: 3
+        })
+    );
+
+    let input = include_str!("examples/24-small.txt").replacen("AND", "NAND", 1);
+    let err = error::<day24::Circuit>(&input);
+    assert_eq!(err.location.map(|location| location.line), Some(8));
+    let input = include_str!("examples/24-small.txt").replacen("-> z00", "-> z00 or so", 1);
+    let err = error::<day24::Circuit>(&input);
+    assert_eq!(err.found.as_deref(), Some("x00 AND y00 -> z00 or so"));
+}
+
+#[test]
+fn rejects_junk_around_claw_machines() {
+    let input = include_str!("examples/13.txt").replacen("Y+34", "Y+34 or so", 1);
+    let err = error::<day13::Arcade>(&input);
+    assert_eq!(err.found.as_deref(), Some("Button A: X+94, Y+34 or so"));
+    let input = include_str!("examples/13.txt").replacen("Prize", "The Prize", 1);
+    let err = error::<day13::Arcade>(&input);
+    assert_eq!(err.location, Some(Location { line: 3, column: 1 }));
+}
+
+#[test]
+fn rejects_malformed_schematics() {
+    let input = include_str!("examples/25.txt").replacen(".####", ".#####", 1);
+    let err = error::<day25::Door>(&input);
+    assert_eq!(err.found.as_deref(), Some(".#####"));
+    assert_eq!(err.location, Some(Location { line: 2, column: 1 }));
+
+    let input = include_str!("examples/25.txt").replacen(".####", ".#x##", 1);
+    let err = error::<day25::Door>(&input);
+    assert_eq!(err.location, Some(Location { line: 2, column: 1 }));
+
+    let input = include_str!("examples/25.txt").replacen(".#...\n.....\n", "", 1);
+    let err = error::<day25::Door>(&input);
+    assert_eq!(err.message, "expected seven rows");
+    assert_eq!(err.location, Some(Location { line: 1, column: 1 }));
+}
+
+#[test]
+fn points_at_a_second_robot() {
+    let input = include_str!("examples/15-small.txt").replacen("#...O..#", "#...O.@#", 1);
+    let err = error::<day15::Input>(&input);
+    assert_eq!(err.location, Some(Location { line: 4, column: 7 }));
+    assert_eq!(err.snippet(&input).unwrap(), "4 | #...O.@#\n  |       ^");
+
+    let input = include_str!("examples/15-small.txt").replacen('@', ".", 1);
+    let err = error::<day15::Input>(&input);
+    assert_eq!(err.to_string(), "expected exactly one '@', found none");
+}
+
+#[test]
+fn unlocated_errors_have_no_snippet() {
+    let err = ParseError::new("expected exactly one robot");
+    assert_eq!(err.clone().locate("#@#").location, None);
+    assert_eq!(err.snippet("#@#"), None);
+    assert_eq!(err.to_string(), "expected exactly one robot");
+    assert_eq!(
+        err.report("map.txt", "#@#"),
+        "could not parse map.txt: expected exactly one robot"
+    );
+}
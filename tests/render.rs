@@ -0,0 +1,253 @@
+use advent_of_code_2024::{
+    day15, day16,
+    grid::Grid,
+    parse,
+    render::{gif, Image, Rgb},
+};
+
+/// a `width` by `height` image of pixels drawn from `colours` colours in a fixed pattern that
+/// looks random, so that LZW finds few repeats
+fn noise(width: usize, height: usize, colours: u32) -> Image {
+    let mut state = 0x2024u64;
+    let grid = Grid::new(width, height, ());
+    Image::from_grid(&grid, |_| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let colour = (state >> 33) as u32 % colours;
+        [colour as u8, (colour >> 8) as u8, 7]
+    })
+}
+
+/// the data of each chunk of a PNG, checking their checksums
+fn chunks(png: &[u8]) -> Vec<(&[u8], &[u8])> {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = vec![];
+    let mut rest = &png[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+        assert_eq!(crc, crc32(&rest[4..8 + len]), "checksum of {kind:?}");
+        chunks.push((kind, data));
+        rest = &rest[12 + len..];
+    }
+    chunks
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// the bytes in a zlib stream of stored blocks, checking its checksum
+fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+    assert_eq!(u16::from_be_bytes([zlib[0], zlib[1]]) % 31, 0);
+    let mut data = vec![];
+    let mut rest = &zlib[2..];
+    loop {
+        let last = rest[0] == 1;
+        assert!(rest[0] <= 1, "only stored blocks");
+        let len = u16::from_le_bytes([rest[1], rest[2]]);
+        assert_eq!(!len, u16::from_le_bytes([rest[3], rest[4]]));
+        data.extend(&rest[5..5 + len as usize]);
+        rest = &rest[5 + len as usize..];
+        if last {
+            break;
+        }
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in &data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    assert_eq!(rest, (b << 16 | a).to_be_bytes());
+    data
+}
+
+/// the size of a GIF and the pixels of each of its frames
+fn decode_gif(gif: &[u8]) -> (usize, usize, Vec<Vec<Rgb>>) {
+    assert_eq!(&gif[..6], b"GIF89a");
+    let width = u16::from_le_bytes([gif[6], gif[7]]) as usize;
+    let height = u16::from_le_bytes([gif[8], gif[9]]) as usize;
+    assert_eq!(gif[10] & 0x80, 0x80, "a global colour table");
+    let size = 2 << (gif[10] & 7);
+    let palette = gif[13..13 + 3 * size]
+        .chunks(3)
+        .map(|colour| [colour[0], colour[1], colour[2]])
+        .collect::<Vec<_>>();
+    let mut at = 13 + 3 * size;
+    let mut frames = vec![];
+    loop {
+        match gif[at] {
+            0x3b => return (width, height, frames),
+            0x21 => {
+                at += 2;
+                while gif[at] != 0 {
+                    at += gif[at] as usize + 1;
+                }
+                at += 1;
+            }
+            0x2c => {
+                let min_bits = gif[at + 10] as u32;
+                at += 11;
+                let mut data = vec![];
+                while gif[at] != 0 {
+                    data.extend(&gif[at + 1..at + 1 + gif[at] as usize]);
+                    at += gif[at] as usize + 1;
+                }
+                at += 1;
+                let pixels = unlzw(&data, min_bits);
+                frames.push(pixels.into_iter().map(|i| palette[i]).collect());
+            }
+            block => panic!("unexpected block {block:#x}"),
+        }
+    }
+}
+
+fn unlzw(data: &[u8], min_bits: u32) -> Vec<usize> {
+    let clear = 1 << min_bits;
+    let end = clear + 1;
+    let mut bit = 0;
+    let mut read = |width: u32| {
+        let code = (0..width).fold(0, |code, i| {
+            let at = bit + i as usize;
+            code | ((data[at / 8] as usize >> (at % 8)) & 1) << i
+        });
+        bit += width as usize;
+        code
+    };
+    let mut width = min_bits + 1;
+    let mut table: Vec<Vec<usize>> = vec![];
+    let mut previous: Option<Vec<usize>> = None;
+    let mut pixels = vec![];
+    loop {
+        let code = read(width);
+        if code == clear {
+            table = (0..=end).map(|i| vec![i]).collect();
+            width = min_bits + 1;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            return pixels;
+        }
+        let entry = match (table.get(code), &previous) {
+            (Some(entry), _) => entry.clone(),
+            (None, Some(previous)) if code == table.len() => {
+                [previous.as_slice(), &previous[..1]].concat()
+            }
+            _ => panic!("code {code} is not in the table"),
+        };
+        if let Some(previous) = previous {
+            if table.len() < 4096 {
+                table.push([previous.as_slice(), &entry[..1]].concat());
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+        }
+        pixels.extend(&entry);
+        previous = Some(entry);
+    }
+}
+
+#[test]
+fn writes_ppm() {
+    let mut image = Image::new(3, 2, [1, 2, 3]);
+    image.set((1, 2), [255, 0, 0]);
+    let mut expected = b"P6\n3 2\n255\n".to_vec();
+    expected.extend([[1, 2, 3]; 5].concat());
+    expected.extend([255, 0, 0]);
+    assert_eq!(image.to_ppm(), expected);
+}
+
+#[test]
+fn writes_png() {
+    for image in [noise(300, 250, 1000), Image::new(0, 0, [0; 3])] {
+        let png = image.to_png();
+        let chunks = chunks(&png);
+        let kinds = chunks.iter().map(|&(kind, _)| kind).collect::<Vec<_>>();
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+        let mut header = (image.width() as u32).to_be_bytes().to_vec();
+        header.extend((image.height() as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+        assert_eq!(chunks[0].1, header);
+        let scanlines = inflate_stored(chunks[1].1);
+        assert_eq!(scanlines.len(), (1 + 3 * image.width()) * image.height());
+        for (row, scanline) in scanlines.chunks(1 + 3 * image.width()).enumerate() {
+            assert_eq!(scanline[0], 0);
+            for (col, pixel) in scanline[1..].chunks(3).enumerate() {
+                assert_eq!(pixel, image.pixel((row, col)));
+            }
+        }
+    }
+}
+
+#[test]
+fn animates_gifs() {
+    // enough noise to fill the code table several times over, and the fewest colours
+    let frames = [noise(200, 150, 256), noise(200, 150, 2), noise(200, 150, 3)];
+    for frames in [&frames[..], &frames[1..2]] {
+        let gif = gif(frames, 5).unwrap();
+        let (width, height, decoded) = decode_gif(&gif);
+        assert_eq!((width, height), (200, 150));
+        assert_eq!(decoded.len(), frames.len());
+        for (frame, decoded) in frames.iter().zip(decoded) {
+            let pixels = Grid::new(200, 150, ())
+                .points()
+                .map(|point| frame.pixel(point));
+            assert!(pixels.eq(decoded));
+        }
+    }
+    assert_eq!(gif(&[noise(40, 40, 1000)], 5), None);
+    assert_eq!(gif(&[Image::new(70_000, 1, [0; 3])], 5), None);
+}
+
+#[test]
+fn scales_up() {
+    let mut image = Image::new(2, 1, [0; 3]);
+    image.set((0, 1), [9; 3]);
+    let scaled = image.scaled(3);
+    assert_eq!((scaled.width(), scaled.height()), (6, 3));
+    assert_eq!(scaled.pixel((2, 2)), [0; 3]);
+    assert_eq!(scaled.pixel((2, 3)), [9; 3]);
+}
+
+#[test]
+fn draws_puzzles() {
+    let warehouse: day15::Input = parse(include_str!("examples/15.txt")).unwrap();
+    let frames = warehouse.frames(1, 1).collect::<Vec<_>>();
+    // one before each of the 700 moves and one after the last
+    assert_eq!(frames.len(), 701);
+    assert_eq!((frames[0].width(), frames[0].height()), (10, 10));
+    assert_ne!(frames[0], frames[700]);
+    assert_eq!(frames[700], warehouse.replay(1, 1).image());
+    let wide = warehouse.frames(3, 2).next().unwrap();
+    assert_eq!((wide.width(), wide.height()), (30, 20));
+
+    let maze: day16::Input = parse(include_str!("examples/16.txt")).unwrap();
+    let image = maze.solve().image();
+    let pixels = Grid::new(image.width(), image.height(), ())
+        .points()
+        .map(|point| image.pixel(point))
+        .collect::<Vec<_>>();
+    // all 45 seats are lit up, but the start and end in colours of their own
+    let (start, end, seat) = (
+        image.pixel((13, 1)),
+        image.pixel((1, 13)),
+        image.pixel((12, 1)),
+    );
+    assert!(start != seat && end != seat && start != end);
+    assert_eq!(pixels.iter().filter(|&&pixel| pixel == seat).count(), 43);
+}
@@ -0,0 +1,84 @@
+use advent_of_code_2024::{
+    day14::{Measure, Robots},
+    parse, Solution,
+};
+
+/// robots that draw a framed tree 31 wide at `tick` among `noise` scattered ones, on the
+/// puzzle's 101 by 103 area
+fn robots(tick: i64, noise: usize) -> String {
+    let mut state = 0x2024u64;
+    let mut random = |below: i64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as i64 % below
+    };
+    let mut picture = vec![];
+    for y in 0..33_i64 {
+        for x in 0..31_i64 {
+            let frame = x == 0 || x == 30 || y == 0 || y == 32;
+            let tree = (2..31).contains(&y) && (15 - x).abs() <= (y - 2) / 2;
+            if frame || tree {
+                picture.push((35 + x, 30 + y));
+            }
+        }
+    }
+    picture.extend((0..noise).map(|_| (random(101), random(103))));
+    picture
+        .into_iter()
+        .map(|(x, y)| {
+            let (vx, vy) = (random(201) - 100, random(201) - 100);
+            let px = (x - vx * tick).rem_euclid(101);
+            let py = (y - vy * tick).rem_euclid(103);
+            format!("p={px},{py} v={vx},{vy}\n")
+        })
+        .collect()
+}
+
+#[test]
+fn finds_the_tree_by_any_measure() {
+    let input = robots(6876, 200);
+    let robots: Robots = parse(&input).unwrap();
+    assert_eq!(robots.part2(), 6876);
+    for measure in [Measure::AxisEntropy, Measure::Quadrants, Measure::Cluster] {
+        let robots: Robots = parse(&input).unwrap();
+        assert_eq!(robots.with_measures(measure, []).tree(), Some(6876));
+    }
+    assert!(robots.largest_cluster(6876) > 250);
+    assert!(robots.quadrant_entropy(6876) < robots.quadrant_entropy(6875) - 1.0);
+}
+
+#[test]
+fn scores_frames_by_measures_together() {
+    let robots: Robots = parse(&robots(1234, 200)).unwrap();
+    let robots = robots.with_measures(Measure::Variance, [Measure::AxisEntropy]);
+    assert_eq!(robots.tree(), Some(1234));
+    let robots = robots.with_measures(Measure::Variance, [Measure::Quadrants]);
+    assert_eq!(robots.tree(), Some(1234));
+}
+
+#[test]
+fn draws_frames() {
+    let robots: Robots = parse(&robots(42, 0)).unwrap();
+    let image = robots.image(42);
+    assert_eq!((image.width(), image.height()), (101, 103));
+    // the top of the frame is one row of robots
+    let robot = image.pixel((30, 35));
+    for col in 0..101 {
+        assert_eq!(image.pixel((30, col)) == robot, (35..66).contains(&col));
+    }
+}
+
+#[test]
+fn needs_coprime_sides_to_combine_the_axes() {
+    let robots: Robots = parse("p=0,0 v=1,2\np=1,1 v=2,2\n").unwrap();
+    assert_eq!(robots.with_area(4, 6).tree(), None);
+}
+
+#[test]
+fn counts_empty_quadrants_as_zero() {
+    // both robots stay in the top left quadrant
+    let input = "p=0,0 v=0,0\np=1,1 v=0,0\n";
+    let robots = parse::<Robots>(input).unwrap().with_area(11, 7);
+    assert_eq!(robots.part1(), 0);
+}
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use advent_of_code_2024::{
+    geometry::{Direction, Point},
+    search::{astar, bfs, dijkstra, Search},
+};
+
+/// a diamond with two equally short routes from 0 to 3, and a dead end at 4
+fn diamond(&node: &u8) -> Vec<(u8, usize)> {
+    match node {
+        0 => vec![(1, 1), (2, 2), (4, 1)],
+        1 => vec![(3, 2)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_records_every_predecessor() {
+    let paths = Search::new()
+        .with_all_predecessors()
+        .dijkstra([0], diamond, |&node| node == 3);
+    assert_eq!(paths.goal_cost(), Some(3));
+    assert_eq!(paths.goals(), [3]);
+    let mut predecessors = paths.predecessors(&3).to_vec();
+    predecessors.sort();
+    assert_eq!(predecessors, [1, 2]);
+    assert_eq!(
+        paths.on_cheapest_paths(paths.goals()),
+        HashSet::from([0, 1, 2, 3])
+    );
+
+    let paths = dijkstra([0], diamond, |&node| node == 3);
+    assert_eq!(paths.predecessors(&3).len(), 1);
+    assert_eq!(paths.path(&3).map(|path| path.len()), Some(3));
+}
+
+#[test]
+fn dijkstra_allows_free_moves_back_to_the_start() {
+    // 0 and 1 are joined both ways at no cost, like turning in place for free
+    let successors = |&node: &u8| match node {
+        0 => vec![(1, 0)],
+        1 => vec![(0, 0), (2, 1)],
+        _ => vec![],
+    };
+    let paths = Search::new()
+        .with_all_predecessors()
+        .dijkstra([0], successors, |&node| node == 2);
+    assert_eq!(paths.goal_cost(), Some(1));
+    assert!(paths.predecessors(&0).is_empty());
+    assert_eq!(paths.path(&2), Some(vec![0, 1, 2]));
+    assert_eq!(
+        paths.on_cheapest_paths(paths.goals()),
+        HashSet::from([0, 1, 2])
+    );
+}
+
+#[test]
+fn bfs_counts_steps() {
+    let paths = bfs(
+        [0u8],
+        |&node| diamond(&node).into_iter().map(|(next, _)| next),
+        |_| false,
+    );
+    assert_eq!(paths.cost(&3), Some(2));
+    assert_eq!(paths.cost(&4), Some(1));
+    assert_eq!(paths.goal_cost(), None);
+    assert_eq!(paths.path(&3).unwrap().first(), Some(&0));
+}
+
+#[test]
+fn astar_stops_at_the_goal() {
+    let goal = Point::new(3, 4);
+    let open = |point: &Point| (0..10).contains(&point.row) && (0..10).contains(&point.col);
+    let mut expanded = 0;
+    let paths = astar(
+        [Point::new(0, 0)],
+        |&point| {
+            expanded += 1;
+            Direction::ALL
+                .map(|direction| (point + direction, 1))
+                .into_iter()
+                .filter(|(next, _)| open(next))
+        },
+        |point| point.manhattan(goal),
+        |&point| point == goal,
+    );
+    assert_eq!(paths.goal_cost(), Some(7));
+    assert_eq!(paths.path(&goal).unwrap().len(), 8);
+    assert!(expanded < 100);
+}
+
+#[test]
+fn astar_reopens_states_an_inconsistent_heuristic_settles_early() {
+    // 1 is on the cheapest path to 4, but its estimate puts it behind the longer way through 2
+    let successors = |&node: &u8| match node {
+        0 => vec![(1, 1), (2, 1)],
+        1 => vec![(3, 1)],
+        2 => vec![(3, 2)],
+        3 => vec![(4, 3)],
+        _ => vec![],
+    };
+    let heuristic = |&node: &u8| if node == 1 { 4 } else { 0 };
+    let paths = Search::new()
+        .with_all_predecessors()
+        .astar([0], successors, heuristic, |&node| node == 4);
+    assert_eq!(paths.goal_cost(), Some(5));
+    assert_eq!(paths.cost(&3), Some(2));
+    assert_eq!(paths.predecessors(&3), [1]);
+    assert_eq!(paths.path(&4), Some(vec![0, 1, 3, 4]));
+}
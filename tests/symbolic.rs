@@ -0,0 +1,52 @@
+// the symbolic executor and the Z3 backend only build with Z3
+#![cfg(feature = "z3")]
+
+use advent_of_code_2024::{
+    day17::{Backend, Program, Query},
+    vm::{self, Machine},
+    Solution,
+};
+
+fn parse<S: Solution>(input: &str) -> S {
+    S::parse(input).unwrap()
+}
+
+/// the default search relies on how puzzle inputs loop, so check it against Z3
+#[test]
+fn day17_backends_agree() {
+    let program: Program<u64, ()> = parse(include_str!("examples/17-2.txt"));
+    assert_eq!(program.quine(Backend::Search), Some(117440));
+    assert_eq!(program.quine(Backend::Z3), Some(117440));
+}
+
+/// the symbolic executor answers more than the quine question
+#[test]
+fn day17_symbolic_queries() {
+    // adv 1, out a, jnz 0 outputs 2,1,0 only when A is 4 or 5
+    let input = include_str!("examples/17-1.txt");
+    let program: Program<u64, ()> = parse(input);
+    let query = Query::new([2, 1, 0])
+        .with_width(8)
+        .with_b(..=0)
+        .with_c(0..1);
+    let mut solutions = program.solve(&query, 10);
+    solutions.sort_by_key(|registers| registers.a);
+    assert_eq!(
+        solutions
+            .iter()
+            .map(|registers| registers.a)
+            .collect::<Vec<_>>(),
+        [4, 5]
+    );
+    assert_eq!(program.minimum(&query.clone().with_a(5..)).unwrap().a, 5);
+    assert_eq!(program.minimum(&query.with_a(6..)), None);
+
+    // B and C are never read, so with 3-bit registers there are 2 * 8 * 8 solutions
+    let query = Query::new([2, 1, 0]).with_width(3);
+    assert_eq!(program.solve(&query, 100).len(), 100);
+    let (_, bytes) = vm::parse(input).unwrap();
+    for registers in program.solve(&query, 200) {
+        assert_eq!(Machine::new(&bytes, registers).run(), Ok(&[2, 1, 0][..]));
+    }
+    assert_eq!(program.solve(&query, 200).len(), 128);
+}
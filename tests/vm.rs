@@ -0,0 +1,196 @@
+use advent_of_code_2024::{
+    day17::Backend,
+    vm::{
+        assemble, disassemble, format_input, parse, parse_program, to_source, Instruction, Machine,
+        Opcode, Operand, Registers, VmError,
+    },
+    Location,
+};
+
+#[test]
+fn runs_the_example() {
+    let (registers, program) = parse(include_str!("examples/17-1.txt")).unwrap();
+    let mut machine = Machine::new(&program, registers);
+    assert_eq!(machine.run().unwrap(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    assert_eq!(machine.registers.a, 0);
+    assert_eq!(machine.current(), None);
+    assert_eq!(machine.step(), Ok(None));
+}
+
+#[test]
+fn steps_one_instruction_at_a_time() {
+    let program = parse_program("Program: 0,1,5,4,3,0").unwrap();
+    let mut machine = Machine::new(&program, Registers { a: 4, b: 0, c: 0 });
+    let mut trace = vec![];
+    machine
+        .run_traced(|machine, instruction| {
+            trace.push((machine.ip, instruction.to_string(), machine.registers.a))
+        })
+        .unwrap();
+    assert_eq!(
+        trace,
+        [
+            (0, "adv 1".to_owned(), 4),
+            (2, "out a".to_owned(), 2),
+            (4, "jnz 0".to_owned(), 2),
+            (0, "adv 1".to_owned(), 2),
+            (2, "out a".to_owned(), 1),
+            (4, "jnz 0".to_owned(), 1),
+            (0, "adv 1".to_owned(), 1),
+            (2, "out a".to_owned(), 0),
+            (4, "jnz 0".to_owned(), 0),
+        ]
+    );
+    assert_eq!(machine.steps(), 9);
+    assert_eq!(machine.output, [2, 1, 0]);
+}
+
+#[test]
+fn disassembles_with_combo_operands() {
+    let program = parse_program("Program: 2,4,1,5,7,5,4,3,0,3,5,6,3,0").unwrap();
+    let listing = disassemble(&program)
+        .into_iter()
+        .map(|(address, instruction)| format!("{address}: {instruction}"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        listing,
+        [
+            "0: bst a",
+            "2: bxl 5",
+            "4: cdv b",
+            "6: bxc 3",
+            "8: adv 3",
+            "10: out c",
+            "12: jnz 0"
+        ]
+    );
+    let reserved = Instruction::decode(Opcode::Out.encode(), 7).unwrap();
+    assert_eq!(reserved.operand(), Operand::Reserved);
+    assert_eq!(reserved.to_string(), "out ?");
+}
+
+#[test]
+fn reports_reserved_operands() {
+    let program = parse_program("Program: 1,2,5,7").unwrap();
+    let mut machine = Machine::new(&program, Registers::default());
+    assert_eq!(machine.run(), Err(VmError::ReservedOperand { ip: 2 }));
+}
+
+#[test]
+fn reports_non_termination() {
+    let program = parse_program("Program: 3,0").unwrap();
+    let mut machine = Machine::new(&program, Registers { a: 1, b: 0, c: 0 }).with_step_budget(1000);
+    assert_eq!(machine.run(), Err(VmError::StepBudget { steps: 1000 }));
+    assert_eq!(
+        VmError::StepBudget { steps: 1000 }.to_string(),
+        "still running after 1000 steps"
+    );
+}
+
+#[test]
+fn runs_long_programs_without_recursing() {
+    let program = [1, 1].repeat(500_000);
+    let mut machine = Machine::new(&program, Registers::default());
+    assert_eq!(machine.run().unwrap(), []);
+    assert_eq!(machine.registers.b, 0);
+    assert_eq!(machine.steps(), 500_000);
+}
+
+#[test]
+fn rejects_malformed_programs() {
+    let err = parse_program("Program: 0,1,5").unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("5"));
+    let err = parse_program("Program: 0,8").unwrap_err();
+    assert_eq!(err.message, "expected a 3-bit number");
+}
+
+#[test]
+fn reads_the_registers_in_order() {
+    let input = format_input(Registers { a: 1, b: 2, c: 3 }, &[0, 1]);
+    assert_eq!(parse(&input).unwrap().0, Registers { a: 1, b: 2, c: 3 });
+    let swapped = input.replacen("Register B", "Register C", 1);
+    let err = parse(&swapped).unwrap_err();
+    assert_eq!(err.message, "expected register B");
+    assert_eq!(err.found.as_deref(), Some("Register C: 2"));
+    let trailing = input.replacen("A: 1", "A: 1 or so", 1);
+    assert_eq!(parse(&trailing).unwrap_err().message, "expected register A");
+}
+
+#[test]
+fn assembles_the_example() {
+    let source = "
+        .a 729
+        loop: adv 1 ; halve A
+              out a
+              jnz loop
+    ";
+    let (registers, program) = assemble(source).unwrap();
+    assert_eq!(
+        format_input(registers, &program),
+        include_str!("examples/17-1.txt")
+    );
+}
+
+#[test]
+fn disassembly_assembles_to_the_same_program() {
+    for input in [
+        include_str!("examples/17-1.txt"),
+        include_str!("examples/17-2.txt"),
+        "Register A: 1\nRegister B: 2\nRegister C: 3\n\nProgram: 4,3,5,7,3,3,3,6,3,1,0,4\n",
+    ] {
+        let (registers, program) = parse(input).unwrap();
+        let source = to_source(registers, &program);
+        assert_eq!(assemble(&source).unwrap(), (registers, program), "{source}");
+    }
+}
+
+#[test]
+fn labels_jump_targets() {
+    let program = parse_program("Program: 0,3,5,4,3,0").unwrap();
+    assert_eq!(
+        to_source(
+            Registers {
+                a: 2024,
+                b: 0,
+                c: 0
+            },
+            &program
+        ),
+        ".a 2024\n.b 0\n.c 0\n\nL0:\n    adv 3     ; 0\n    out a     ; 2\n    jnz L0    ; 4\n"
+    );
+}
+
+#[test]
+fn locates_assembly_errors() {
+    let source = "start:\n    adv 3\n    out d\n    jnz start\n";
+    let err = assemble(source).unwrap_err().locate(source);
+    assert_eq!(err.location, Some(Location { line: 3, column: 9 }));
+    assert_eq!(err.message, "expected a 3-bit number or a register");
+
+    let err = assemble("    mul 2").unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("mul"));
+
+    let err = assemble("jnz nowhere").unwrap_err();
+    assert_eq!(err.message, "expected a 3-bit address or a label");
+
+    let source = "adv 1\nadv 1\nadv 1\nadv 1\nend:\njnz end";
+    let err = assemble(source).unwrap_err();
+    assert_eq!(err.message, "expected a label within the first 8 addresses");
+
+    let err = assemble("out").unwrap_err();
+    assert_eq!(err.message, "expected an operand for out");
+    assert_eq!(
+        assemble("x: bxc\nx: bxc").unwrap_err().message,
+        "expected a label not used before"
+    );
+}
+
+#[test]
+fn parses_quine_backends() {
+    let err = "fast".parse::<Backend>().unwrap_err();
+    assert_eq!(err.found.as_deref(), Some("fast"));
+    #[cfg(not(feature = "z3"))]
+    assert!("z3".parse::<Backend>().is_err());
+    #[cfg(feature = "z3")]
+    assert_eq!("z3".parse(), Ok(Backend::Z3));
+}
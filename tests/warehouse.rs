@@ -0,0 +1,98 @@
+use advent_of_code_2024::{
+    day15::{Input, MoveOutcome},
+    geometry::{Direction, Point},
+    parse, Solution,
+};
+
+#[test]
+fn reports_each_move() {
+    let input: Input = parse("#######\n#.....#\n#.OO@.#\n#.#...#\n#######\n\n<<<v").unwrap();
+    let mut warehouse = input.warehouse(1, 1);
+    let pushed = vec![Point::new(2, 2), Point::new(2, 3)];
+    assert_eq!(
+        warehouse.apply(Direction::Left),
+        MoveOutcome::Moved { pushed }
+    );
+    assert_eq!(warehouse.apply(Direction::Left), MoveOutcome::Blocked);
+    assert_eq!(warehouse.robot(), Point::new(2, 3));
+    assert_eq!(
+        warehouse.to_string(),
+        "#######\n#.....#\n#OO@..#\n#.#...#\n#######\n"
+    );
+
+    let mut warehouse = input.warehouse(2, 1);
+    warehouse.apply(Direction::Left);
+    assert_eq!(
+        warehouse.to_string(),
+        "##############\n##..........##\n##.[][]@....##\n##..##......##\n##############\n"
+    );
+}
+
+#[test]
+fn pushes_boxes_resting_on_one_another() {
+    // shifts the lower box half a tile right, so that it holds up both boxes above
+    let input: Input =
+        parse("#######\n#.....#\n#..OO.#\n#@.O..#\n#.....#\n#######\n\n>>>>v>^").unwrap();
+    let mut warehouse = input.warehouse(2, 1);
+    let (&last, moves) = input.moves().split_last().unwrap();
+    for &m in moves {
+        warehouse.apply(m);
+    }
+    let pushed = [(2, 6), (2, 8), (3, 7)].map(|(row, col)| Point::new(row, col));
+    assert_eq!(
+        warehouse.apply(last),
+        MoveOutcome::Moved {
+            pushed: pushed.to_vec()
+        }
+    );
+    assert_eq!(
+        warehouse.to_string(),
+        "##############\n##....[][]..##\n##.....[]...##\n##.....@....##\n##..........##\n##############\n"
+    );
+}
+
+#[test]
+fn pushes_boxes_of_any_size() {
+    let input: Input = parse("#######\n#.....#\n#..O..#\n#..@..#\n#######\n\n^^^").unwrap();
+    let mut warehouse = input.warehouse(3, 2);
+    assert_eq!(warehouse.robot(), Point::new(6, 9));
+    let outcomes = input.moves().iter().map(|&m| warehouse.apply(m));
+    let pushed = |row| MoveOutcome::Moved {
+        pushed: vec![Point::new(row, 9)],
+    };
+    assert!(outcomes.eq([pushed(4), pushed(3), MoveOutcome::Blocked]));
+    let map = warehouse.to_string();
+    let rows = map.lines().collect::<Vec<_>>();
+    assert_eq!(rows.len(), 10);
+    assert_eq!(rows[1], "#".repeat(21));
+    assert_eq!(rows[2], "###......[-]......###");
+    assert_eq!(rows[3], "###......[-]......###");
+    assert_eq!(rows[4], "###......@........###");
+    assert_eq!(warehouse.gps(), 209);
+}
+
+#[test]
+fn undoes_every_move() {
+    let input: Input = parse(include_str!("examples/15.txt")).unwrap();
+    for (width, height) in [(1, 1), (2, 1), (3, 1), (2, 3)] {
+        let mut warehouse = input.warehouse(width, height);
+        let start = warehouse.to_string();
+        let mut states = vec![];
+        for &m in input.moves() {
+            states.push(warehouse.to_string());
+            warehouse.apply(m);
+        }
+        match (width, height) {
+            (1, 1) => assert_eq!(warehouse.gps(), input.part1()),
+            (2, 1) => assert_eq!(warehouse.gps(), input.part2()),
+            _ => {}
+        }
+        assert_eq!(warehouse.history().len(), input.moves().len());
+        while let Some((m, _)) = warehouse.undo() {
+            assert_eq!(Some(&m), input.moves().get(warehouse.history().len()));
+            assert_eq!(Some(warehouse.to_string()), states.pop());
+        }
+        assert_eq!(warehouse.to_string(), start);
+        assert_eq!(warehouse.undo(), None);
+    }
+}